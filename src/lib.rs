@@ -1,34 +1,6 @@
 #![doc = include_str!("../README.md")]
 
-mod config;
-mod context;
-mod error;
-mod interpreter;
-mod modificator;
-mod nature;
-mod package;
-mod reader;
-
-#[macro_use]
-extern crate lazy_static;
-
-use config::Config;
-use context::Context;
-use interpreter::ts::Indexer;
-use nature::Natures;
 use proc_macro::TokenStream;
-use quote::ToTokens;
-use std::{convert::TryInto, sync::RwLock};
-use syn::{parse_macro_input, Item};
-
-lazy_static! {
-    #[doc(hidden)]
-    static ref CONFIG: RwLock<Config> = RwLock::new(Config::default());
-    #[doc(hidden)]
-    static ref NATURES: RwLock<Natures> = RwLock::new(Natures::new());
-    #[doc(hidden)]
-    static ref TS_IMPORTS: RwLock<Indexer> = RwLock::new(Indexer::default());
-}
 
 /// Binds given entity with TypeScript type and generates JavaScript representation for it. This can be applied to:
 ///
@@ -94,26 +66,36 @@ lazy_static! {
 ///
 #[proc_macro_attribute]
 pub fn tslink(args: TokenStream, item: TokenStream) -> TokenStream {
-    if let Err(err) = config::setup() {
-        return syn::Error::new_spanned(item.to_string(), err.to_string())
-            .into_compile_error()
-            .into();
-    }
-    let item_ref = item.clone();
-    let context: Context = parse_macro_input!(args as Context);
-    if context.ignore_self() {
-        item
-    } else {
-        let mut natures = NATURES.write().expect("Get access to list of entities");
-        let mut item = parse_macro_input!(item as Item);
-        let cfg = CONFIG.read().expect("Read configuration");
-        if let Err(err) = reader::read(&mut item, &mut natures, context, &cfg) {
-            let str_err = err.to_string();
-            return TryInto::<syn::Error>::try_into(err)
-                .unwrap_or(syn::Error::new_spanned(item_ref.to_string(), str_err))
-                .into_compile_error()
-                .into();
-        }
-        proc_macro::TokenStream::from(item.to_token_stream())
-    }
+    tslink_build::macros::tslink(
+        args.into(),
+        item.into(),
+        proc_macro::Span::call_site().local_file(),
+    )
+    .into()
+}
+
+/// Generates all artifacts (`*.js`, `*.d.ts`, `*.ts`, `package.json`) once for all entities collected
+/// by `#[tslink]` so far.
+///
+/// Should be placed at the end of the crate root (after all modules) together with the setting
+/// `deferred_generation = true` in `[package.metadata.tslink]`. With this setting `#[tslink]` only
+/// collects entities, and files are written once by `finalize!()` instead of being regenerated
+/// after each item. Without this setting the macro does nothing.
+///
+/// Only entities collected before the expansion of `finalize!()` are written, so it should be
+/// expanded after all `#[tslink]` items (see "Deferred generation" in README).
+///
+/// ```
+/// # #[macro_use] extern crate tslink;
+/// # use tslink::tslink;
+/// #[tslink]
+/// struct MyStruct {
+///     pub p8: u8,
+/// }
+///
+/// tslink::finalize!();
+/// ```
+#[proc_macro]
+pub fn finalize(_input: TokenStream) -> TokenStream {
+    tslink_build::macros::finalize().into()
 }
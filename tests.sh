@@ -12,6 +12,12 @@ cd ../constant
 yarn run test
 cd ../node-bindgen
 yarn run test
+cd ../napi-rs
+yarn run test
+cd ../validation
+yarn run test
+cd ../wasm-bindgen
+yarn run test
 cd ../modules
 sh ./run_test.sh
 cd ../../examples/node_bindgen
@@ -1,6 +1,9 @@
+// Generated by tslink 0.4.2 from crate tslink-test 0.1.0.
+// DO NOT EDIT: manual changes will be lost on the next build.
+// tslink-hash: b0d63fa4f7a9dc9c
+export const A = "Hello";
 export const B = 42;
+export const C = 42;
 export const D = 42;
 export const F = [1, 2, 3, 4];
-export const C = 42;
-export const A = "Hello";
 export const G = ["1", "2", "3", "4"];
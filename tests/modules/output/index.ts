@@ -1,6 +1,9 @@
-export { FieldA } from "./module_a";
-export { FieldB } from "./module_a";
-export { StructA } from "./module_a";
+// Generated by tslink 0.4.2 from crate tslink-test 0.1.0.
+// DO NOT EDIT: manual changes will be lost on the next build.
+// tslink-hash: dfaef5d2cb7e66f9
 export { EntityA } from "./module_b";
-export { EntityB } from "./module_b";
-export { OtherStruct } from "./module_b";
+export type { EntityB } from "./module_b";
+export { FieldA } from "./module_a";
+export type { FieldB } from "./module_a";
+export type { OtherStruct } from "./module_b";
+export type { StructA } from "./module_a";
@@ -1,14 +1,17 @@
-export interface StructA {
-    a: FieldA;
-    b: FieldB;
+// Generated by tslink 0.4.2 from crate tslink-test 0.1.0.
+// DO NOT EDIT: manual changes will be lost on the next build.
+// tslink-hash: fdb30f2d929194b7
+export enum FieldA {
+    One,
+    Two,
+    Three,
 }
 export interface FieldB {
     One?: string;
     Two?: [number, number];
     Three?: FieldA;
 }
-export enum FieldA {
-    One,
-    Two,
-    Three,
+export interface StructA {
+    a: FieldA;
+    b: FieldB;
 }
@@ -1,3 +1,6 @@
+// Generated by tslink 0.4.2 from crate tslink-test 0.1.0.
+// DO NOT EDIT: manual changes will be lost on the next build.
+// tslink-hash: 6871bb1a054568f5
 export enum EntityA {
     One,
     Two,
@@ -8,9 +11,9 @@ export interface EntityB {
     Two?: [number, number];
     Three?: EntityA;
 }
-import { FieldB } from "./module_a";
-import { StructA } from "./module_a";
-import { FieldA } from "./module_a";
+import type { FieldA } from "./module_a";
+import type { FieldB } from "./module_a";
+import type { StructA } from "./module_a";
 export interface OtherStruct {
     a: EntityA;
     b: EntityB;
@@ -0,0 +1,99 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use tslink::tslink;
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+struct AsyncErrorA {
+    msg: String,
+    code: usize,
+}
+
+impl From<serde_json::Error> for AsyncErrorA {
+    fn from(value: serde_json::Error) -> Self {
+        AsyncErrorA {
+            msg: value.to_string(),
+            code: 1,
+        }
+    }
+}
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+struct AsyncDataA {
+    pub a: i32,
+    pub b: i32,
+    pub c: String,
+}
+
+struct AsyncErrorB(String);
+
+impl From<serde_json::Error> for AsyncErrorB {
+    fn from(value: serde_json::Error) -> Self {
+        AsyncErrorB(value.to_string())
+    }
+}
+
+impl From<AsyncErrorB> for napi::Error {
+    fn from(value: AsyncErrorB) -> Self {
+        napi::Error::from_reason(value.0)
+    }
+}
+
+async fn inc_later(value: i32) -> i32 {
+    value + 1
+}
+
+#[napi]
+pub struct StructAsyncMethods {}
+
+#[tslink(class)]
+#[napi]
+impl StructAsyncMethods {
+    #[tslink(constructor)]
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tslink(
+        data = "AsyncDataA",
+        result = "json",
+        error = "json",
+        snake_case_naming
+    )]
+    #[napi]
+    pub async fn get_data(&self, data: String) -> Result<AsyncDataA, AsyncErrorA> {
+        Ok(AsyncDataA {
+            a: data.a + 1,
+            b: data.b + 1,
+            c: format!("{}{}", data.c, data.c),
+        })
+    }
+
+    #[tslink(
+        data = "AsyncDataA",
+        result = "json",
+        error = "json",
+        snake_case_naming
+    )]
+    #[napi]
+    pub async fn get_error(&self, data: String) -> Result<AsyncDataA, AsyncErrorA> {
+        Err(AsyncErrorA {
+            msg: data.c,
+            code: 666,
+        })
+    }
+
+    #[tslink(data = "AsyncDataA", result = "json", snake_case_naming)]
+    #[napi]
+    pub async fn get_data_awaited(&self, data: String) -> Result<AsyncDataA, AsyncErrorB> {
+        let a = inc_later(data.a).await;
+        let b = inc_later(data.b).await;
+        Ok(AsyncDataA {
+            a,
+            b,
+            c: format!("{}{}", data.c, data.c),
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use tslink::tslink;
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+struct CustomError {
+    msg: String,
+    code: usize,
+}
+
+#[napi]
+pub struct StructErrorHandeling {}
+
+#[tslink(class)]
+#[napi]
+impl StructErrorHandeling {
+    #[tslink(constructor)]
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tslink(error = "json", snake_case_naming)]
+    #[napi]
+    pub fn test_of_exception_with_custom_error(&self) -> Result<i32, CustomError> {
+        Err(CustomError {
+            msg: String::from("test"),
+            code: 666,
+        })
+    }
+
+    #[tslink(error = "json", snake_case_naming, exception_suppression)]
+    #[napi]
+    pub fn test_of_exception_suppression_with_custom_error(&self) -> Result<i32, CustomError> {
+        Err(CustomError {
+            msg: String::from("test"),
+            code: 666,
+        })
+    }
+
+    #[tslink(error = "json", snake_case_naming, exception_suppression)]
+    #[napi]
+    pub fn test_of_error_support_ok(&self) -> Result<i32, CustomError> {
+        Ok(666)
+    }
+}
@@ -0,0 +1,80 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use tslink::tslink;
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+struct ErrorA {
+    msg: String,
+    code: usize,
+}
+
+impl From<serde_json::Error> for ErrorA {
+    fn from(value: serde_json::Error) -> Self {
+        ErrorA {
+            msg: value.to_string(),
+            code: 1,
+        }
+    }
+}
+
+impl From<ErrorA> for napi::Error {
+    fn from(value: ErrorA) -> Self {
+        napi::Error::from_reason(value.msg)
+    }
+}
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+struct DataA {
+    pub a: i32,
+    pub b: i32,
+    pub c: String,
+}
+
+#[tslink(data = "DataA", error = "json", snake_case_naming)]
+#[napi]
+pub fn sum_data(data: String) -> Result<i32, ErrorA> {
+    Ok(data.a + data.b)
+}
+
+#[napi]
+pub struct StructCustomData {}
+
+#[tslink(class)]
+#[napi]
+impl StructCustomData {
+    #[tslink(constructor)]
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tslink(
+        data = "DataA",
+        result = "json",
+        error = "json",
+        snake_case_naming,
+        exception_suppression
+    )]
+    #[napi]
+    pub fn get_data(&self, data: String) -> Result<DataA, ErrorA> {
+        Ok(DataA {
+            a: data.a + 1,
+            b: data.b + 1,
+            c: format!("{}{}", data.c, data.c),
+        })
+    }
+
+    #[tslink(data = "DataA", result = "json", snake_case_naming)]
+    #[napi]
+    pub fn get_data_or_reason(&self, data: String) -> Result<DataA, ErrorA> {
+        if data.a < 0 {
+            return Err(ErrorA {
+                msg: String::from("negative"),
+                code: 2,
+            });
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,4 @@
+mod asyncness;
+mod errors;
+mod json;
+mod statics;
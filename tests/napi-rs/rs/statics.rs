@@ -0,0 +1,36 @@
+use napi_derive::napi;
+use tslink::tslink;
+
+#[tslink(class)]
+#[napi]
+pub struct StructStaticMethods {
+    value: u32,
+}
+
+#[tslink(class)]
+#[napi]
+impl StructStaticMethods {
+    #[tslink(constructor)]
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self { value: 0 }
+    }
+
+    #[tslink(snake_case_naming)]
+    #[napi(factory)]
+    pub fn with_value(value: u32) -> Self {
+        Self { value }
+    }
+
+    #[tslink(snake_case_naming)]
+    #[napi]
+    pub fn max_value() -> u32 {
+        u32::MAX
+    }
+
+    #[tslink(snake_case_naming)]
+    #[napi]
+    pub fn get_value(&self) -> u32 {
+        self.value
+    }
+}
@@ -0,0 +1,79 @@
+import { Group } from "./common";
+import { StructAsyncMethods } from "binding";
+
+const tests = new Group("Async Methods Tests");
+const struct = new StructAsyncMethods();
+
+{
+    const test = tests.test("getData");
+    const timeout = setTimeout(() => {
+        test.fail(`Function getData() didn't call callback`);
+    }, 50);
+    struct
+        .getData({ a: 1, b: 2, c: "test" })
+        .then((result) => {
+            test.assert(result.a).msg("Value of result.a invalid").equal(2);
+            test.assert(result.b).msg("Value of result.b invalid").equal(3);
+            test.assert(result.c)
+                .msg("Value of result.s invalid")
+                .equal("testtest");
+            test.success();
+        })
+        .catch((err: Error) => {
+            test.fail(
+                `Function getData() of StructAsyncMethods returns error: ${err.message}`
+            );
+        })
+        .finally(() => {
+            clearTimeout(timeout);
+        });
+}
+
+{
+    const test = tests.test("getError");
+    const timeout = setTimeout(() => {
+        test.fail(`Function getError() didn't call callback`);
+    }, 50);
+    struct
+        .getError({ a: 1, b: 2, c: "test" })
+        .then(() => {
+            test.fail(`Function getError() should reject`);
+        })
+        .catch((err: Error) => {
+            test.assert((err as any).err)
+                .msg("Value of err invalid")
+                .type("object");
+            test.assert((err as any).err.msg)
+                .msg("Value of err.msg invalid")
+                .equal("test");
+            test.success();
+        })
+        .finally(() => {
+            clearTimeout(timeout);
+        });
+}
+
+{
+    const test = tests.test("getDataAwaited");
+    const timeout = setTimeout(() => {
+        test.fail(`Function getDataAwaited() didn't call callback`);
+    }, 50);
+    struct
+        .getDataAwaited({ a: 1, b: 2, c: "test" })
+        .then((result) => {
+            test.assert(result.a).msg("Value of result.a invalid").equal(2);
+            test.assert(result.b).msg("Value of result.b invalid").equal(3);
+            test.assert(result.c)
+                .msg("Value of result.c invalid")
+                .equal("testtest");
+            test.success();
+        })
+        .catch((err: Error) => {
+            test.fail(
+                `Function getDataAwaited() of StructAsyncMethods returns error: ${err.message}`
+            );
+        })
+        .finally(() => {
+            clearTimeout(timeout);
+        });
+}
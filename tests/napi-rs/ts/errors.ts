@@ -0,0 +1,47 @@
+import { Group } from "./common";
+import { StructErrorHandeling } from "binding";
+
+const tests = new Group("Error Handeling Tests");
+const struct = new StructErrorHandeling();
+
+{
+    const test = tests.test("testOfExceptionWithCustomError");
+    try {
+        const _ = struct.testOfExceptionWithCustomError();
+        test.fail("function should throw exception");
+    } catch (err) {
+        if (err instanceof Error) {
+            test.assert((err as any).err)
+                .msg("Value of err invalid")
+                .type("object");
+            test.assert((err as any).err.code)
+                .msg("Value of err.code invalid")
+                .equal(666);
+        } else {
+            test.fail("Value of err invalid");
+        }
+        test.success();
+    }
+}
+
+{
+    const test = tests.test("testOfExceptionSuppressionWithCustomError");
+    try {
+        const err = struct.testOfExceptionSuppressionWithCustomError();
+        if (err instanceof Error) {
+            test.assert(err.err).msg("Value of err invalid").type("object");
+        } else {
+            test.fail("Value of err invalid");
+        }
+        test.success();
+    } catch (err) {
+        test.fail("function should not throw exception");
+    }
+}
+
+{
+    const test = tests.test("testOfErrorSupportOk");
+    const result = struct.testOfErrorSupportOk();
+    test.assert(result).msg("Value of result invalid").equal(666);
+    test.success();
+}
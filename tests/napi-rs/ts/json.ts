@@ -0,0 +1,44 @@
+import { Group } from "./common";
+import { StructCustomData, sumData } from "binding";
+
+const tests = new Group("Custom Data Tests");
+const struct = new StructCustomData();
+
+{
+    const test = tests.test("sumData");
+    const result = sumData({ a: 1, b: 2, c: "test" });
+    test.assert(result).msg("Value of result invalid").equal(3);
+    test.success();
+}
+
+{
+    const test = tests.test("getData");
+    const result = struct.getData({ a: 1, b: 2, c: "test" });
+    if (result instanceof Error) {
+        test.fail(
+            `Function getData() of StructCustomData returns error: ${result.message}`
+        );
+    } else {
+        test.assert(result.a).msg("Value of result.a invalid").equal(2);
+        test.assert(result.b).msg("Value of result.b invalid").equal(3);
+        test.assert(result.c)
+            .msg("Value of result.s invalid")
+            .equal("testtest");
+        test.success();
+    }
+}
+
+{
+    const test = tests.test("getDataOrReason");
+    try {
+        const _ = struct.getDataOrReason({ a: -1, b: 2, c: "test" });
+        test.fail("function should throw exception");
+    } catch (err) {
+        if (err instanceof Error) {
+            test.assert(err.message).msg("Value of err invalid").equal("negative");
+        } else {
+            test.fail("Value of err invalid");
+        }
+        test.success();
+    }
+}
@@ -0,0 +1,4 @@
+import "./json";
+import "./errors";
+import "./asyncness";
+import "./statics";
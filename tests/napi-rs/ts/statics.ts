@@ -0,0 +1,25 @@
+import { Group } from "./common";
+import { StructStaticMethods } from "binding";
+
+const tests = new Group("Static Methods Tests");
+
+{
+    const test = tests.test("withValue");
+    const instance = StructStaticMethods.withValue(5);
+    test.assert(instance instanceof StructStaticMethods)
+        .msg("Instance isn't wrapped")
+        .beTrue();
+    test.assert(instance.getValue()).msg("Value of getValue invalid").equal(5);
+    test.assert(new StructStaticMethods().getValue())
+        .msg("Value of getValue invalid")
+        .equal(0);
+    test.success();
+}
+
+{
+    const test = tests.test("maxValue");
+    test.assert(StructStaticMethods.maxValue())
+        .msg("Value of maxValue invalid")
+        .equal(4294967295);
+    test.success();
+}
@@ -36,6 +36,25 @@ struct AsyncDataB {
     pub d: Option<String>,
 }
 
+#[derive(Debug)]
+struct AsyncErrorB(String);
+
+impl From<serde_json::Error> for AsyncErrorB {
+    fn from(value: serde_json::Error) -> Self {
+        AsyncErrorB(value.to_string())
+    }
+}
+
+impl std::fmt::Display for AsyncErrorB {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+async fn inc_later(value: i32) -> i32 {
+    value + 1
+}
+
 #[derive(Debug, Clone)]
 struct StructAsyncMethods {}
 
@@ -113,6 +132,18 @@ impl StructAsyncMethods {
         })
     }
 
+    #[tslink(data = "AsyncDataA", result = "json", snake_case_naming)]
+    #[node_bindgen]
+    pub async fn get_data_awaited(&self, data: String) -> Result<AsyncDataA, AsyncErrorB> {
+        let a = inc_later(data.a).await;
+        let b = inc_later(data.b).await;
+        Ok(AsyncDataA {
+            a,
+            b,
+            c: format!("{}{}", data.c, data.c),
+        })
+    }
+
     #[tslink(error = "json", snake_case_naming, exception_suppression)]
     #[node_bindgen]
     async fn test_of_error_support_ok(&self) -> Result<i32, AsyncErrorA> {
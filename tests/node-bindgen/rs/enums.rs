@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use tslink::tslink;
+
+#[tslink]
+#[derive(Serialize, Deserialize, PartialEq)]
+enum Level {
+    Low,
+    High,
+}
+
+#[tslink]
+impl Level {
+    pub fn label(&self) -> String {
+        match self {
+            Level::Low => String::from("low"),
+            Level::High => String::from("high"),
+        }
+    }
+
+    pub fn parse(s: String) -> Self {
+        if s == "high" {
+            Level::High
+        } else {
+            Level::Low
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            Level::Low => Level::High,
+            Level::High => Level::Low,
+        }
+    }
+
+    pub fn is_higher(&self, other: Level) -> bool {
+        self == &Level::High && other == Level::Low
+    }
+}
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+enum Shape {
+    Circle(f64),
+    Square(f64),
+}
+
+#[tslink]
+impl Shape {
+    pub fn area(&self) -> f64 {
+        match self {
+            Shape::Circle(r) => 3.0 * r * r,
+            Shape::Square(a) => a * a,
+        }
+    }
+}
@@ -2,6 +2,7 @@ mod asyncness;
 mod callbacks;
 pub mod constant;
 mod constructors;
+mod enums;
 mod errors;
 mod json;
 mod optional;
@@ -108,6 +108,31 @@ const struct = new StructAsyncMethods();
         });
 }
 
+{
+    const test = tests.test("getDataAwaited");
+    const timeout = setTimeout(() => {
+        test.fail(`Function getDataAwaited() didn't call callback`);
+    }, 50);
+    struct
+        .getDataAwaited({ a: 1, b: 2, c: "test" })
+        .then((result) => {
+            test.assert(result.a).msg("Value of result.a invalid").equal(2);
+            test.assert(result.b).msg("Value of result.b invalid").equal(3);
+            test.assert(result.c)
+                .msg("Value of result.c invalid")
+                .equal("testtest");
+            test.success();
+        })
+        .catch((err: Error) => {
+            test.fail(
+                `Function getDataAwaited() of StructAsyncMethods returns error: ${err.message}`
+            );
+        })
+        .finally(() => {
+            clearTimeout(timeout);
+        });
+}
+
 {
     const test = tests.test("testOfErrorSupportOk");
     const timeout = setTimeout(() => {
@@ -0,0 +1,41 @@
+import { Group } from "./common";
+import { Level, Shape } from "binding";
+
+const tests = new Group("Enum Methods Tests");
+
+{
+    const test = tests.test("label");
+    test.assert(Level.label(Level.Low)).msg("Value of label invalid").equal("low");
+    test.assert(Level.label(Level.High)).msg("Value of label invalid").equal("high");
+    test.success();
+}
+
+{
+    const test = tests.test("parse");
+    test.assert(Level.parse("high")).msg("Value of parse invalid").equal(Level.High);
+    test.assert(Level.parse("low")).msg("Value of parse invalid").equal(Level.Low);
+    test.success();
+}
+
+{
+    const test = tests.test("toggle");
+    test.assert(Level.toggle(Level.Low)).msg("Value of toggle invalid").equal(Level.High);
+    test.success();
+}
+
+{
+    const test = tests.test("isHigher");
+    test.assert(Level.isHigher(Level.High, Level.Low))
+        .msg("Value of isHigher invalid")
+        .beTrue();
+    test.assert(Level.isHigher(Level.Low, Level.High))
+        .msg("Value of isHigher invalid")
+        .equal(false);
+    test.success();
+}
+
+{
+    const test = tests.test("area");
+    test.assert(Shape.area({ Square: 2 })).msg("Value of area invalid").equal(4);
+    test.success();
+}
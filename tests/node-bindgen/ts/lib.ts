@@ -1,4 +1,5 @@
 import "./json";
+import "./enums";
 import "./errors";
 import "./callbacks";
 import "./consturctors";
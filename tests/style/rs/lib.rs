@@ -0,0 +1,36 @@
+extern crate tslink;
+use tslink::tslink;
+
+#[tslink]
+pub const GREETING: &str = "It's a \"greeting\"; // not a comment";
+
+#[tslink]
+pub const PATTERN: &str = "/* ${not} a template */";
+
+#[tslink(class)]
+pub struct Counter {
+    pub value: u32,
+}
+
+#[tslink(class)]
+impl Counter {
+    #[tslink(constructor)]
+    pub fn new() -> Self {
+        Self { value: 0 }
+    }
+
+    pub fn inc(&mut self, step: u8) -> u32 {
+        self.value += step as u32;
+        self.value
+    }
+}
+
+#[tslink]
+pub fn label(name: String, level: u8) -> String {
+    format!("{name}: {level}")
+}
+
+#[tslink]
+pub async fn delayed(ms: u32) -> u32 {
+    ms
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tslink::tslink;
+
+#[tslink(target = "./ts/bindings/data.ts")]
+pub const LIMIT: u8 = 1_0;
+
+#[tslink(target = "./ts/bindings/data.ts")]
+pub const RATIO: f64 = -1.5f64;
+
+#[tslink(target = "./ts/bindings/data.ts")]
+pub const SIZE: u32 = 2 * 5;
+
+#[tslink(target = "./ts/bindings/data.ts")]
+#[derive(Serialize, Deserialize)]
+pub enum Role {
+    Admin,
+    Guest,
+}
+
+#[tslink(target = "./ts/bindings/data.ts")]
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    pub id: u32,
+    pub name: String,
+    pub age: u8,
+    pub tags: Vec<String>,
+    pub role: Role,
+    pub scores: Option<HashMap<String, i16>>,
+}
+
+#[tslink(target = "./ts/bindings/data.ts")]
+#[derive(Serialize, Deserialize)]
+pub enum Event {
+    Created(User),
+    Moved(u32, u32),
+    Removed { id: u32 },
+    Cleared,
+}
@@ -0,0 +1,82 @@
+use crate::data::{Event, Role, User};
+use node_bindgen::derive::node_bindgen;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tslink::tslink;
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+pub struct Error {
+    pub msg: String,
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error {
+            msg: err.to_string(),
+        }
+    }
+}
+
+#[tslink(result = "json", snake_case_naming)]
+#[node_bindgen]
+fn create_user(id: u32, name: String) -> Result<User, Error> {
+    Ok(User {
+        id,
+        name,
+        age: 42,
+        tags: vec![String::from("new")],
+        role: Role::Guest,
+        scores: Some(HashMap::from([(String::from("total"), -1)])),
+    })
+}
+
+#[tslink(user = "User", result = "json", snake_case_naming)]
+#[node_bindgen]
+fn rename_user(user: String, name: String) -> Result<User, Error> {
+    let mut user = user;
+    user.name = name;
+    Ok(user)
+}
+
+#[tslink(result = "json", snake_case_naming)]
+#[node_bindgen]
+fn remove_user(id: u32) -> Result<Event, Error> {
+    Ok(Event::Removed { id })
+}
+
+#[tslink(result = "json", snake_case_naming)]
+#[node_bindgen]
+fn list_events() -> Result<Vec<Event>, Error> {
+    Ok(vec![
+        Event::Created(User {
+            id: 1,
+            name: String::from("Alice"),
+            age: 42,
+            tags: vec![],
+            role: Role::Admin,
+            scores: None,
+        }),
+        Event::Moved(1, 2),
+        Event::Removed { id: 1 },
+        Event::Cleared,
+    ])
+}
+
+#[tslink(snake_case_naming)]
+#[node_bindgen]
+fn sum(a: u8, b: i16, c: Option<u32>) -> i64 {
+    a as i64 + b as i64 + c.unwrap_or(0) as i64
+}
+
+#[tslink(snake_case_naming)]
+#[node_bindgen]
+fn total(values: Vec<u8>) -> u32 {
+    values.iter().map(|v| *v as u32).sum()
+}
+
+#[tslink(snake_case_naming)]
+#[node_bindgen]
+async fn inc_later(value: u8) -> u32 {
+    value as u32 + 1
+}
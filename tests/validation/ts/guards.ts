@@ -0,0 +1,72 @@
+import { Group } from "./common";
+import {
+    assertUser,
+    createUser,
+    isEvent,
+    isRole,
+    isUser,
+    removeUser,
+} from "binding";
+import {
+    Role,
+    assertUser as assertUserData,
+    isRole as isRoleData,
+    isUser as isUserData,
+} from "./bindings/data";
+
+const tests = new Group("Type Guards Tests");
+
+{
+    const test = tests.test("guards of lib.js");
+    const user = createUser(1, "Alice");
+    test.assert(isUser(user)).msg("Valid user is rejected").beTrue();
+    test.assert(isUser({ ...user, scores: null })).msg("User without scores is rejected").beTrue();
+    test.assert(isUser({ ...user, age: "42" })).msg("Invalid age is accepted").equal(false);
+    test.assert(isUser({ ...user, scores: { total: "1" } }))
+        .msg("Invalid score is accepted")
+        .equal(false);
+    test.assert(isUser({ ...user, tags: "new" })).msg("Invalid tags are accepted").equal(false);
+    test.assert(isRole("Admin")).msg("Valid role is rejected").beTrue();
+    test.assert(isRole("Owner")).msg("Invalid role is accepted").equal(false);
+    test.assert(isEvent(removeUser(1))).msg("Valid event is rejected").beTrue();
+    test.assert(isEvent({ Removed: { id: "1" } })).msg("Invalid event is accepted").equal(false);
+    try {
+        assertUser({});
+        test.fail("Invalid user is asserted");
+    } catch (e) {
+        test.assert(e instanceof TypeError).msg("Error isn't TypeError").beTrue();
+    }
+    test.success();
+}
+
+{
+    const test = tests.test("guards of *.ts");
+    const user = {
+        id: 1,
+        name: "Alice",
+        age: 42,
+        tags: ["new"],
+        role: Role.Guest,
+        scores: new Map([["total", -1]]),
+    };
+    test.assert(isUserData(user)).msg("Valid user is rejected").beTrue();
+    test.assert(isUserData({ ...user, scores: { total: -1 } }))
+        .msg("Object is rejected instead of Map")
+        .beTrue();
+    test.assert(isUserData({ ...user, scores: new Map([["total", "-1"]]) }))
+        .msg("Map with invalid values is accepted")
+        .equal(false);
+    test.assert(isUserData({ ...user, scores: { total: "-1" } }))
+        .msg("Object with invalid values is accepted")
+        .equal(false);
+    test.assert(isUserData({ ...user, role: "Guest" })).msg("Invalid role is accepted").equal(false);
+    test.assert(isRoleData(Role.Admin)).msg("Valid role is rejected").beTrue();
+    assertUserData(user);
+    try {
+        assertUserData({ ...user, id: "1" });
+        test.fail("Invalid user is asserted");
+    } catch (e) {
+        test.assert(e instanceof TypeError).msg("Error isn't TypeError").beTrue();
+    }
+    test.success();
+}
@@ -0,0 +1,4 @@
+import "./schema";
+import "./zod";
+import "./guards";
+import "./validate";
@@ -0,0 +1,153 @@
+import { Group } from "./common";
+import { LIMIT, RATIO, SIZE, createUser, listEvents, removeUser, renameUser } from "binding";
+
+import * as fs from "fs";
+import * as path from "path";
+
+const tests = new Group("JSON Schema Tests");
+
+type Schema = { [key: string]: any };
+
+const bundle: Schema = JSON.parse(
+    fs.readFileSync(path.resolve(__dirname, "../dist/schema.json"), "utf8")
+);
+
+// Validates the value against the subset of JSON Schema used by tslink
+function errors(schema: Schema, value: any, at: string = "value"): string[] {
+    if (schema["$ref"] !== undefined) {
+        const name = (schema["$ref"] as string).replace("#/$defs/", "");
+        return errors(bundle["$defs"][name], value, at);
+    }
+    if (schema["anyOf"] !== undefined || schema["oneOf"] !== undefined) {
+        const variants: Schema[] = schema["anyOf"] ?? schema["oneOf"];
+        const passed = variants.filter((variant) => errors(variant, value, at).length === 0);
+        return passed.length > 0 ? [] : [`${at}: no variant matches`];
+    }
+    if (schema["const"] !== undefined && JSON.stringify(schema["const"]) !== JSON.stringify(value)) {
+        return [`${at}: expected ${JSON.stringify(schema["const"])}`];
+    }
+    if (schema["enum"] !== undefined && !schema["enum"].includes(value)) {
+        return [`${at}: expected one of ${schema["enum"]}`];
+    }
+    switch (schema["type"]) {
+        case "null":
+            return value === null ? [] : [`${at}: expected null`];
+        case "string":
+            return typeof value === "string" ? [] : [`${at}: expected string`];
+        case "boolean":
+            return typeof value === "boolean" ? [] : [`${at}: expected boolean`];
+        case "number":
+        case "integer": {
+            if (typeof value !== "number" || (schema["type"] === "integer" && !Number.isInteger(value))) {
+                return [`${at}: expected ${schema["type"]}`];
+            }
+            if (schema["minimum"] !== undefined && value < schema["minimum"]) {
+                return [`${at}: less than ${schema["minimum"]}`];
+            }
+            if (schema["maximum"] !== undefined && value > schema["maximum"]) {
+                return [`${at}: greater than ${schema["maximum"]}`];
+            }
+            return [];
+        }
+        case "array":
+            return Array.isArray(value)
+                ? value.flatMap((item, i) => errors(schema["items"], item, `${at}[${i}]`))
+                : [`${at}: expected array`];
+        case "object": {
+            if (typeof value !== "object" || value === null || Array.isArray(value)) {
+                return [`${at}: expected object`];
+            }
+            const properties: Schema = schema["properties"] ?? {};
+            const found: string[] = [];
+            for (const key of schema["required"] ?? []) {
+                if (!(key in value)) {
+                    found.push(`${at}.${key}: required`);
+                }
+            }
+            for (const [key, item] of Object.entries(value)) {
+                if (properties[key] !== undefined) {
+                    found.push(...errors(properties[key], item, `${at}.${key}`));
+                } else if (schema["additionalProperties"] === false) {
+                    found.push(`${at}.${key}: unexpected`);
+                } else if (typeof schema["additionalProperties"] === "object") {
+                    found.push(...errors(schema["additionalProperties"], item, `${at}.${key}`));
+                }
+            }
+            if (schema["minProperties"] !== undefined && Object.keys(value).length < schema["minProperties"]) {
+                found.push(`${at}: too few properties`);
+            }
+            if (schema["maxProperties"] !== undefined && Object.keys(value).length > schema["maxProperties"]) {
+                found.push(`${at}: too many properties`);
+            }
+            return found;
+        }
+        default:
+            return [];
+    }
+}
+
+{
+    const test = tests.test("constants");
+    test.assert(bundle["$defs"]["LIMIT"]["const"]).msg("Invalid const of LIMIT").equal(LIMIT);
+    test.assert(bundle["$defs"]["LIMIT"]["maximum"]).msg("Invalid maximum of LIMIT").equal(255);
+    test.assert(bundle["$defs"]["RATIO"]["const"]).msg("Invalid const of RATIO").equal(RATIO);
+    test.assert(bundle["$defs"]["RATIO"]["type"]).msg("Invalid type of RATIO").equal("number");
+    test.assert(bundle["$defs"]["SIZE"]["const"]).msg("Expression of SIZE is described with const").equal(undefined);
+    test.assert(errors(bundle["$defs"]["SIZE"], SIZE).join("; ")).msg("SIZE doesn't match schema").equal("");
+    test.success();
+}
+
+{
+    const test = tests.test("bounds of integers");
+    const properties = bundle["$defs"]["User"]["properties"];
+    test.assert(properties["age"]["minimum"]).msg("Invalid minimum of u8").equal(0);
+    test.assert(properties["age"]["maximum"]).msg("Invalid maximum of u8").equal(255);
+    test.assert(properties["id"]["maximum"]).msg("Invalid maximum of u32").equal(4294967295);
+    const scores = properties["scores"]["anyOf"][0]["additionalProperties"];
+    test.assert(scores["minimum"]).msg("Invalid minimum of i16").equal(-32768);
+    test.assert(scores["maximum"]).msg("Invalid maximum of i16").equal(32767);
+    test.success();
+}
+
+{
+    const test = tests.test("results match schemas");
+    const user = createUser(1, "Alice");
+    test.assert(errors(bundle["$defs"]["User"], user).join("; "))
+        .msg("Result of createUser doesn't match schema")
+        .equal("");
+    const renamed = renameUser(user, "Bob");
+    test.assert(errors(bundle["$defs"]["User"], renamed).join("; "))
+        .msg("Result of renameUser doesn't match schema")
+        .equal("");
+    test.assert(errors(bundle["$defs"]["Event"], removeUser(1)).join("; "))
+        .msg("Result of removeUser doesn't match schema")
+        .equal("");
+    // Variants without data are serialized as strings
+    for (const event of listEvents()) {
+        test.assert(errors(bundle["$defs"]["Event"], event).join("; "))
+            .msg(`Event ${JSON.stringify(event)} doesn't match schema`)
+            .equal("");
+    }
+    test.success();
+}
+
+{
+    const test = tests.test("invalid values don't match schemas");
+    const user = createUser(1, "Alice");
+    test.assert(errors(bundle["$defs"]["User"], { ...user, age: 256 }).length)
+        .msg("Value out of u8 matches schema")
+        .equal(1);
+    test.assert(errors(bundle["$defs"]["User"], { ...user, role: "Owner" }).length)
+        .msg("Unknown variant matches schema")
+        .equal(1);
+    test.assert(errors(bundle["$defs"]["User"], { ...user, extra: true }).length)
+        .msg("Unknown field matches schema")
+        .equal(1);
+    test.assert(errors(bundle["$defs"]["Event"], "Removed").length)
+        .msg("Variant with data matches schema as string")
+        .equal(1);
+    test.assert(errors(bundle["$defs"]["Event"], { Cleared: null }).length)
+        .msg("Variant without data matches schema as object")
+        .equal(1);
+    test.success();
+}
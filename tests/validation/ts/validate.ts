@@ -0,0 +1,56 @@
+import { Group, Test } from "./common";
+import { incLater, sum, total } from "binding";
+
+const tests = new Group("Validate Args Tests");
+
+function rejects(test: Test, call: () => unknown, message: string) {
+    try {
+        call();
+        test.fail("Invalid argument is passed to native module");
+    } catch (e) {
+        test.assert(e instanceof TypeError).msg("Error isn't TypeError").beTrue();
+        test.assert((e as TypeError).message).msg("Invalid message").equal(message);
+    }
+}
+
+{
+    const test = tests.test("valid arguments are passed");
+    test.assert(sum(1, -2, 3)).msg("Invalid sum").equal(2);
+    test.assert(sum(255, 32767, null)).msg("Invalid sum without optional").equal(33022);
+    test.assert(total([1, 2, 3])).msg("Invalid total").equal(6);
+    test.assert(total([])).msg("Invalid total of empty").equal(0);
+    test.success();
+}
+
+{
+    const test = tests.test("invalid arguments are rejected");
+    rejects(test, () => sum(256, 0, null), 'Invalid argument "a" of "sum": expected u8');
+    rejects(test, () => sum(1.5, 0, null), 'Invalid argument "a" of "sum": expected u8');
+    rejects(test, () => sum(0, -32769, null), 'Invalid argument "b" of "sum": expected i16');
+    rejects(test, () => sum(0, 0, -1), 'Invalid argument "c" of "sum": expected Option<u32>');
+    rejects(test, () => sum("1" as any, 0, null), 'Invalid argument "a" of "sum": expected u8');
+    rejects(test, () => total([1, 256]), 'Invalid argument "values" of "total": expected Vec<u8>');
+    rejects(test, () => total(1 as any), 'Invalid argument "values" of "total": expected Vec<u8>');
+    test.success();
+}
+
+{
+    const test = tests.test("async functions reject invalid arguments");
+    const timeout = setTimeout(() => {
+        test.fail(`Function incLater() didn't call callback`);
+    }, 50);
+    incLater(-1)
+        .then(() => {
+            test.fail("Invalid argument is passed to native module");
+        })
+        .catch((err: Error) => {
+            test.assert(err instanceof TypeError).msg("Error isn't TypeError").beTrue();
+            test.assert(err.message)
+                .msg("Invalid message")
+                .equal('Invalid argument "value" of "incLater": expected u8');
+            test.success();
+        })
+        .finally(() => {
+            clearTimeout(timeout);
+        });
+}
@@ -0,0 +1,58 @@
+import { Group } from "./common";
+import { createUser, renameUser } from "binding";
+import { Role, EventSchema, RoleSchema, UserSchema } from "./bindings/data";
+
+const tests = new Group("Zod Tests");
+
+{
+    const test = tests.test("parse valid data");
+    const user = UserSchema.parse({
+        id: 1,
+        name: "Alice",
+        age: 42,
+        tags: ["new"],
+        role: Role.Guest,
+        scores: { total: -1 },
+    });
+    test.assert(user.name).msg("Invalid name").equal("Alice");
+    test.assert(user.role).msg("Invalid role").equal(Role.Guest);
+    test.assert(user.scores instanceof Map).msg("Scores aren't converted into Map").beTrue();
+    test.assert(user.scores?.get("total")).msg("Invalid score").equal(-1);
+    test.assert(RoleSchema.parse(Role.Admin)).msg("Invalid role").equal(Role.Admin);
+    const event = EventSchema.parse({ Removed: { id: 2 } });
+    test.assert(event.Removed?.id).msg("Invalid event").equal(2);
+    test.success();
+}
+
+{
+    const test = tests.test("reject invalid data");
+    const invalid = [
+        { id: 1, name: "Alice", age: "42", tags: [], role: Role.Guest, scores: null },
+        { id: 1, name: "Alice", age: 42, tags: [1], role: Role.Guest, scores: null },
+        { id: 1, name: "Alice", age: 42, tags: [], role: 5, scores: null },
+        { id: 1, name: "Alice", age: 42, tags: [], role: Role.Guest },
+    ];
+    for (const value of invalid) {
+        test.assert(UserSchema.safeParse(value).success)
+            .msg(`Invalid value is parsed: ${JSON.stringify(value)}`)
+            .equal(false);
+    }
+    test.assert(EventSchema.safeParse({ Removed: { id: "2" } }).success)
+        .msg("Invalid event is parsed")
+        .equal(false);
+    test.success();
+}
+
+{
+    // With validate_args the type guard rejects the argument before the schema
+    const test = tests.test("bound arguments are validated");
+    const user = createUser(1, "Alice");
+    test.assert(renameUser(user, "Bob").name).msg("Invalid name").equal("Bob");
+    try {
+        renameUser({ ...user, age: "42" } as any, "Bob");
+        test.fail("Invalid argument is passed to native module");
+    } catch (e) {
+        test.assert(e instanceof TypeError).msg("Error isn't TypeError").beTrue();
+    }
+    test.success();
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use tslink::tslink;
+use wasm_bindgen::prelude::*;
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+pub struct PointError {
+    pub msg: String,
+}
+
+impl From<serde_json::Error> for PointError {
+    fn from(err: serde_json::Error) -> Self {
+        PointError {
+            msg: err.to_string(),
+        }
+    }
+}
+
+#[tslink]
+#[derive(Serialize, Deserialize)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[tslink]
+impl Direction {
+    pub fn step(&self) -> i32 {
+        match self {
+            Direction::Left => -1,
+            Direction::Right => 1,
+        }
+    }
+}
+
+#[tslink(
+    point = "Point",
+    direction = "Direction",
+    result = "json",
+    error = "json",
+    snake_case_naming
+)]
+#[wasm_bindgen(js_name = "movePoint")]
+pub fn move_point(point: String, direction: String, distance: i32) -> Result<Point, PointError> {
+    if distance < 0 {
+        return Err(PointError {
+            msg: String::from("Negative distance"),
+        });
+    }
+    Ok(Point {
+        x: point.x + direction.step() * distance,
+        y: point.y,
+    })
+}
+
+#[tslink(error = "json", exception_suppression, snake_case_naming)]
+#[wasm_bindgen(js_name = "checkDistance")]
+pub fn check_distance(distance: i32) -> Result<i32, PointError> {
+    if distance < 0 {
+        Err(PointError {
+            msg: String::from("Negative distance"),
+        })
+    } else {
+        Ok(distance)
+    }
+}
+
+#[tslink(snake_case_naming)]
+#[wasm_bindgen(js_name = "toBig")]
+pub fn to_big(value: u64) -> u64 {
+    value * 2
+}
@@ -0,0 +1,11 @@
+import { Group } from "./common";
+import { Direction } from "binding";
+
+const tests = new Group("Enum Methods Tests");
+
+{
+    const test = tests.test("step");
+    test.assert(Direction.step(Direction.Left)).msg("Value of step invalid").equal(-1);
+    test.assert(Direction.step(Direction.Right)).msg("Value of step invalid").equal(1);
+    test.success();
+}
@@ -0,0 +1,54 @@
+import { Group } from "./common";
+import { Direction, checkDistance, movePoint, toBig } from "binding";
+
+const tests = new Group("Functions Tests");
+
+{
+    const test = tests.test("movePoint");
+    const point = movePoint({ x: 1, y: 2 }, Direction.Right, 3);
+    test.assert(point.x).msg("Value of point.x invalid").equal(4);
+    test.assert(point.y).msg("Value of point.y invalid").equal(2);
+    test.success();
+}
+
+{
+    const test = tests.test("movePoint with error");
+    try {
+        const _ = movePoint({ x: 1, y: 2 }, Direction.Left, -1);
+        test.fail("function should throw exception");
+    } catch (err) {
+        if (err instanceof Error) {
+            test.assert((err as any).err).msg("Value of err invalid").type("object");
+            test.assert((err as any).err.msg)
+                .msg("Value of err.msg invalid")
+                .equal("Negative distance");
+        } else {
+            test.fail("Value of err invalid");
+        }
+        test.success();
+    }
+}
+
+{
+    const test = tests.test("checkDistance");
+    try {
+        test.assert(checkDistance(1)).msg("Value of distance invalid").equal(1);
+        const err = checkDistance(-1);
+        if (err instanceof Error) {
+            test.assert(err.err?.msg).msg("Value of err.msg invalid").equal("Negative distance");
+        } else {
+            test.fail("Value of err invalid");
+        }
+        test.success();
+    } catch (err) {
+        test.fail("function should not throw exception");
+    }
+}
+
+{
+    const test = tests.test("toBig");
+    test.assert(toBig(BigInt(2) ** BigInt(40)))
+        .msg("Value of toBig invalid")
+        .equal(BigInt(2) ** BigInt(41));
+    test.success();
+}
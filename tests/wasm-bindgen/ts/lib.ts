@@ -0,0 +1,2 @@
+import "./functions";
+import "./enums";
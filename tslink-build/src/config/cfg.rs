@@ -0,0 +1,484 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, io::Error};
+use toml::Table;
+
+const TSLINK_CARGO_KEY: &str = "tslink";
+const PACKAGE_CARGO_KEY: &str = "package";
+const METADATA_CARGO_KEY: &str = "metadata";
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SnakeCaseNaming {
+    Methods,
+    Fields,
+}
+
+/// Module system of the generated `lib.js`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ModuleFormat {
+    /// CommonJS: `require(...)` and `exports.X = X`.
+    #[default]
+    Cjs,
+    /// ES module: `import`/`export`; the native module is loaded with `createRequire(import.meta.url)`.
+    Esm,
+    /// CommonJS `lib.js`/`lib.cjs` and ES module `lib.mjs` with matching `exports` map in `package.json`.
+    Dual,
+}
+
+impl TryFrom<&str> for ModuleFormat {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<ModuleFormat, Self::Error> {
+        if value == ModuleFormat::Cjs.to_string() {
+            Ok(ModuleFormat::Cjs)
+        } else if value == ModuleFormat::Esm.to_string() {
+            Ok(ModuleFormat::Esm)
+        } else if value == ModuleFormat::Dual.to_string() {
+            Ok(ModuleFormat::Dual)
+        } else {
+            Err(Error::other(format!(
+                "Unknown option for module_format option: \"{value}\""
+            )))
+        }
+    }
+}
+
+impl fmt::Display for ModuleFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Cjs => "cjs",
+                Self::Esm => "esm",
+                Self::Dual => "dual",
+            }
+        )
+    }
+}
+
+/// Crate used to build the native module; defines conventions of the generated `lib.js` glue
+/// and of functions modified by `#[tslink]`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Backend {
+    /// `node-bindgen`: errors are thrown as values returned by functions (JSON strings).
+    #[default]
+    NodeBindgen,
+    /// `napi-rs`: errors are `napi::Error` and thrown as `Error` objects with the JSON string
+    /// in `message`.
+    NapiRs,
+    /// `wasm-bindgen`: `lib.js` wraps the JavaScript module generated by wasm-bindgen instead
+    /// of loading a `.node` file; errors are thrown as values (JSON strings).
+    WasmBindgen,
+}
+
+impl TryFrom<&str> for Backend {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Backend, Self::Error> {
+        if value == Backend::NodeBindgen.to_string() {
+            Ok(Backend::NodeBindgen)
+        } else if value == Backend::NapiRs.to_string() {
+            Ok(Backend::NapiRs)
+        } else if value == Backend::WasmBindgen.to_string() {
+            Ok(Backend::WasmBindgen)
+        } else {
+            Err(Error::other(format!(
+                "Unknown option for backend option: \"{value}\""
+            )))
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::NodeBindgen => "node-bindgen",
+                Self::NapiRs => "napi-rs",
+                Self::WasmBindgen => "wasm-bindgen",
+            }
+        )
+    }
+}
+
+/// Code style of generated `*.ts`, `*.d.ts` and `*.js` files (`[package.metadata.tslink.style]`).
+///
+/// Interpreters render code through [`crate::interpreter::Emitter`], which applies the style.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    /// Indentation of one level.
+    pub indent: Indent,
+    /// Quotes of string literals.
+    pub quotes: Quotes,
+    /// If `false`, statements and members aren't terminated with semicolons.
+    pub semicolons: bool,
+    /// Line endings.
+    pub line_endings: LineEndings,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            indent: Indent::Spaces(4),
+            quotes: Quotes::default(),
+            semicolons: true,
+            line_endings: LineEndings::default(),
+        }
+    }
+}
+
+/// Indentation of one level (`indent_style = "spaces" | "tabs"`, `indent_width = 4`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Indent {
+    Spaces(usize),
+    Tabs,
+}
+
+/// Quotes of string literals (`quotes = "double" | "single"`).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Quotes {
+    #[default]
+    Double,
+    Single,
+}
+
+impl Quotes {
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::Double => '"',
+            Self::Single => '\'',
+        }
+    }
+}
+
+impl TryFrom<&str> for Quotes {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Quotes, Self::Error> {
+        if value == Quotes::Double.to_string() {
+            Ok(Quotes::Double)
+        } else if value == Quotes::Single.to_string() {
+            Ok(Quotes::Single)
+        } else {
+            Err(Error::other(format!(
+                "Unknown option for quotes option: \"{value}\""
+            )))
+        }
+    }
+}
+
+impl fmt::Display for Quotes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Double => "double",
+                Self::Single => "single",
+            }
+        )
+    }
+}
+
+/// Line endings (`line_endings = "lf" | "crlf"`).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LineEndings {
+    #[default]
+    Lf,
+    Crlf,
+}
+
+impl TryFrom<&str> for LineEndings {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<LineEndings, Self::Error> {
+        if value == LineEndings::Lf.to_string() {
+            Ok(LineEndings::Lf)
+        } else if value == LineEndings::Crlf.to_string() {
+            Ok(LineEndings::Crlf)
+        } else {
+            Err(Error::other(format!(
+                "Unknown option for line_endings option: \"{value}\""
+            )))
+        }
+    }
+}
+
+impl fmt::Display for LineEndings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Lf => "lf",
+                Self::Crlf => "crlf",
+            }
+        )
+    }
+}
+
+/// Specifies how a Rust `enum` should be represented in the generated TypeScript definitions.
+///
+/// This enum controls the output format for enums with mixed variants — including unit, tuple, and struct-like variants —
+/// to ensure compatibility and clarity on the TypeScript side.
+///
+/// The following Rust example:
+/// ```ignore
+/// enum SomeEnum {
+///     One,
+///     Two,
+///     Three(u8),
+///     Four(u8, u16, u32),
+///     Five((String, String)),
+///     Six(Vec<u8>),
+/// }
+/// ```
+/// can be transformed into TypeScript in different ways depending on the selected representation.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum EnumRepresentation {
+    /// Generates a single interface with optional discriminant fields.
+    ///
+    /// ```ignore
+    /// export interface SomeEnum {
+    ///     One?: null;
+    ///     Two?: null;
+    ///     Three?: number;
+    ///     Four?: [number, number, number];
+    ///     Five?: [string, string];
+    ///     Six?: number[];
+    /// }
+    /// ```
+    DiscriminatedUnion,
+
+    /// Generates a flat tagged union using TypeScript's `type` union.
+    ///
+    /// This is the default.
+    ///
+    /// ```ignore
+    /// export type SomeEnum =
+    ///     { One: null; } |
+    ///     { Two: null; } |
+    ///     { Three: number; } |
+    ///     { Four: [number, number, number]; } |
+    ///     { Five: [string, string]; } |
+    ///     { Six: number[]; };
+    /// ```
+    #[default]
+    Flat,
+
+    /// Generates a mixed union of string literals and tagged object variants.
+    ///
+    /// Useful when some variants are unit-like and others carry data.
+    ///
+    /// ```ignore
+    /// export type SomeEnum =
+    ///     "One" |
+    ///     "Two" |
+    ///     "Three" |
+    ///     { Four: [number, number, number]; } |
+    ///     { Five: [string, string]; } |
+    ///     { Six: number[]; };
+    /// ```
+    Union,
+}
+
+impl TryFrom<&str> for EnumRepresentation {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<EnumRepresentation, Self::Error> {
+        if value == EnumRepresentation::DiscriminatedUnion.to_string() {
+            Ok(EnumRepresentation::DiscriminatedUnion)
+        } else if value == EnumRepresentation::Flat.to_string() {
+            Ok(EnumRepresentation::Flat)
+        } else if value == EnumRepresentation::Union.to_string() {
+            Ok(EnumRepresentation::Union)
+        } else {
+            Err(Error::other(format!(
+                "Unknown option for enum_representation option: \"{value}\""
+            )))
+        }
+    }
+}
+
+impl fmt::Display for EnumRepresentation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::DiscriminatedUnion => "discriminated",
+                Self::Flat => "flat",
+                Self::Union => "union",
+            }
+        )
+    }
+}
+
+impl TryFrom<&str> for SnakeCaseNaming {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<SnakeCaseNaming, Self::Error> {
+        if value == SnakeCaseNaming::Methods.to_string() {
+            Ok(SnakeCaseNaming::Methods)
+        } else if value == SnakeCaseNaming::Fields.to_string() {
+            Ok(SnakeCaseNaming::Fields)
+        } else {
+            Err(Error::other(format!(
+                "Unknown option for snake_case_naming option: \"{value}\""
+            )))
+        }
+    }
+}
+
+impl fmt::Display for SnakeCaseNaming {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Methods => "methods",
+                Self::Fields => "fields",
+            }
+        )
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct Cfg {
+    pub node: Option<String>,
+    pub js: Option<String>,
+    pub dts: Option<String>,
+    pub package: Option<String>,
+    pub snake_case_naming: Option<String>,
+    pub exception_suppression: bool,
+    pub int_over_32_as_big_int: bool,
+    pub type_map: HashMap<String, String>,
+    pub enum_representation: EnumRepresentation,
+    pub skip_private: bool,
+    pub deferred_generation: bool,
+    pub module_format: Option<String>,
+    pub style: StyleCfg,
+    pub force_overwrite: bool,
+    pub import_extension: Option<String>,
+    pub json_schema: Option<String>,
+    pub zod: bool,
+    pub type_guards: bool,
+    pub validate_args: bool,
+    pub backend: Option<String>,
+}
+
+/// Raw settings of `[package.metadata.tslink.style]`.
+#[derive(Deserialize, Debug, Default)]
+pub struct StyleCfg {
+    pub indent_style: Option<String>,
+    pub indent_width: Option<i64>,
+    pub quotes: Option<String>,
+    pub semicolons: Option<bool>,
+    pub line_endings: Option<String>,
+}
+
+impl Cfg {
+    pub fn new(cargo: &Table) -> Self {
+        if cargo.contains_key(TSLINK_CARGO_KEY) {
+            // Support 0.4.1 > versions
+            cargo.get(TSLINK_CARGO_KEY)
+        } else {
+            // Default settings place after 0.4.1
+            cargo
+                .get(PACKAGE_CARGO_KEY)
+                .and_then(|p| p.get(METADATA_CARGO_KEY))
+                .and_then(|m| m.get(TSLINK_CARGO_KEY))
+        }
+        .and_then(|v| v.as_table())
+        .map(|settings| Cfg {
+            node: settings
+                .get("node")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            js: settings
+                .get("js")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            dts: settings
+                .get("dts")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            package: settings
+                .get("package")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            snake_case_naming: settings
+                .get("snake_case_naming")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            exception_suppression: settings
+                .get("exception_suppression")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            int_over_32_as_big_int: settings
+                .get("int_over_32_as_big_int")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            type_map: settings
+                .get("type_map")
+                .and_then(|v| v.as_table())
+                .map(|m| {
+                    m.iter()
+                        .filter_map(|(key, value)| {
+                            value.as_str().map(|v| (key.clone(), v.to_string()))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            enum_representation: settings
+                .get("enum_representation")
+                .and_then(|v| v.as_str().map(|s| s.try_into().unwrap_or_default()))
+                .unwrap_or_default(),
+            skip_private: settings
+                .get("skip_private")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            deferred_generation: settings
+                .get("deferred_generation")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            module_format: settings
+                .get("module_format")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            style: settings
+                .get("style")
+                .and_then(|v| v.as_table())
+                .map(|style| StyleCfg {
+                    indent_style: style
+                        .get("indent_style")
+                        .and_then(|v| v.as_str().map(|v| v.to_string())),
+                    indent_width: style.get("indent_width").and_then(|v| v.as_integer()),
+                    quotes: style
+                        .get("quotes")
+                        .and_then(|v| v.as_str().map(|v| v.to_string())),
+                    semicolons: style.get("semicolons").and_then(|v| v.as_bool()),
+                    line_endings: style
+                        .get("line_endings")
+                        .and_then(|v| v.as_str().map(|v| v.to_string())),
+                })
+                .unwrap_or_default(),
+            force_overwrite: settings
+                .get("force_overwrite")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            import_extension: settings
+                .get("import_extension")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            json_schema: settings
+                .get("json_schema")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+            zod: settings
+                .get("zod")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            type_guards: settings
+                .get("type_guards")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            validate_args: settings
+                .get("validate_args")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+            backend: settings
+                .get("backend")
+                .and_then(|v| v.as_str().map(|v| v.to_string())),
+        })
+        .unwrap_or_default()
+    }
+}
@@ -0,0 +1,367 @@
+use crate::{error::E, package::value, CONFIG};
+use cfg::{
+    Backend, Cfg, EnumRepresentation, Indent, LineEndings, ModuleFormat, Quotes, SnakeCaseNaming,
+    Style,
+};
+use convert_case::{Case, Casing};
+use std::{
+    collections::{HashMap, HashSet},
+    default::Default,
+    env, fs,
+    path::{Path, PathBuf},
+};
+use toml::Table;
+use uuid::Uuid;
+
+pub mod cfg;
+
+const TSLINK_BUILD_ENV: &str = "TSLINK_BUILD";
+
+/// Global configuration settings for the code generation process.
+///
+/// `Config` aggregates environment-specific, Cargo-derived, and user-defined settings
+/// that influence how Rust types and functions are translated into JavaScript/TypeScript code.
+///
+/// It is typically constructed once (e.g., from `Cargo.toml` or a macro environment)
+/// and then queried during macro execution or file generation.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Indicates whether the configuration has been initialized.
+    ///
+    /// Used to prevent re-initialization or partial access before setup.
+    inited: bool,
+
+    /// Raw parsed contents of `[package.metadata.tslink]` or `[tslink]` from `Cargo.toml`, if available.
+    ///
+    /// May be used to retrieve user-defined keys not explicitly mapped to struct fields.
+    cargo: Option<Table>,
+
+    /// Controls whether file system writes (e.g., `.ts`, `.d.ts`) are allowed.
+    ///
+    /// Typically disabled during procedural macro expansion or in sandboxed environments.
+    pub io_allowed: bool,
+
+    /// Optional filename for the generated Node.js module (e.g., `"lib.node"`).
+    ///
+    /// Used when producing JavaScript FFI wrappers or distributing compiled bindings.
+    pub node_mod_filename: Option<String>,
+
+    /// Optional path to the output directory for the Node.js module or compiled artifacts.
+    pub node_mod_dist: Option<PathBuf>,
+
+    /// Path of the generated JavaScript entry (`js = "./dist/index.js"`).
+    ///
+    /// By default `lib.js` in the folder of the node module.
+    pub js_file: PathBuf,
+
+    /// Path of the generated declaration file (`dts = "./types/index.d.ts"`).
+    ///
+    /// By default `lib.d.ts` in the folder of the node module.
+    pub dts_file: PathBuf,
+
+    /// Path of the generated `package.json` (`package = "./package.json"`).
+    ///
+    /// By default `package.json` in the folder of the node module. All paths in `package.json`
+    /// are relative to its folder.
+    pub package_file: PathBuf,
+
+    /// Path of the generated JSON Schema bundle (`json_schema = "./dist/schema.json"`).
+    ///
+    /// The bundle isn't generated by default.
+    pub json_schema: Option<PathBuf>,
+
+    /// Set of entities (fields, methods, etc.) that should be renamed from `snake_case` to `camelCase` in TypeScript.
+    ///
+    /// Useful for maintaining idiomatic JS/TS naming conventions while preserving Rust semantics.
+    pub snake_case_naming: HashSet<SnakeCaseNaming>,
+
+    /// Enables exception suppression in generated JS/TS code.
+    ///
+    /// When active, JS wrappers will wrap function calls in `try/catch` blocks,
+    /// and TypeScript return types will be `T | Error`.
+    pub exception_suppression: bool,
+
+    /// Treats all integer types with width > 32 bits (e.g., `i64`, `u64`) as `bigint` in TypeScript.
+    ///
+    /// Prevents precision loss and improves correctness across language boundaries.
+    pub int_over_32_as_big_int: bool,
+
+    /// Manual mapping of Rust type names (as strings) to target TypeScript types.
+    ///
+    /// This allows overriding or customizing how specific Rust types appear in `.ts` or `.d.ts` files.
+    pub type_map: HashMap<String, String>,
+
+    /// Determines how enums are rendered in TypeScript (e.g., flat union, discriminated union).
+    ///
+    /// Controlled via `[tslink.enum_representation]` or macro-level overrides.
+    pub enum_representation: EnumRepresentation,
+
+    /// Skips non-`pub` fields and methods of structs represented as classes.
+    ///
+    /// Disabled by default; a single field or method can be kept with `#[tslink(include)]`.
+    /// Non-`pub` members with other own attributes are reported as errors.
+    pub skip_private: bool,
+
+    /// Defers generation of artifacts until `tslink::finalize!()`.
+    ///
+    /// By default all files are regenerated after each `#[tslink]` item; with this option
+    /// items are only collected and files are generated once.
+    pub deferred_generation: bool,
+
+    /// Module system of the generated `lib.js` (`module_format = "cjs" | "esm" | "dual"`).
+    pub module_format: ModuleFormat,
+
+    /// Crate used to build the native module
+    /// (`backend = "node-bindgen" | "napi-rs" | "wasm-bindgen"`).
+    pub backend: Backend,
+
+    /// Code style of generated `*.ts`, `*.d.ts` and `*.js` files (`[tslink.style]`).
+    pub style: Style,
+
+    /// Extension of module specifiers in imports and exports of generated `*.ts` files
+    /// (`import_extension = ".js"`, required by Node ESM resolution). Empty by default.
+    pub import_extension: String,
+
+    /// Overwrites generated files even if they have been modified manually.
+    ///
+    /// By default tslink refuses to overwrite a file whose content doesn't match the hash in its header.
+    pub force_overwrite: bool,
+
+    /// Renders zod schemas (`UserSchema`) next to declarations in `*.ts` files and validates
+    /// arguments bound to JSON (`data = "User"`) in `lib.js` with them.
+    pub zod: bool,
+
+    /// Renders type guards (`isUser`) and assertions (`assertUser`) of interfaces, type aliases
+    /// and enums in `*.ts` files and `lib.js`.
+    pub type_guards: bool,
+
+    /// Checks arguments of functions and methods in `lib.js` before calling the native module.
+    pub validate_args: bool,
+
+    /// Folder of the crate's `Cargo.toml`.
+    pub manifest_dir: PathBuf,
+
+    /// Folder, which relative paths of artifacts are resolved from.
+    ///
+    /// By default paths are relative to the current folder (see [`output_dir`]).
+    pub output_dir: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn overwrite(&mut self, cargo: Table, io_allowed: bool) -> Result<(), E> {
+        self.inited = true;
+        self.io_allowed = io_allowed;
+        let cfg = Cfg::new(&cargo);
+        self.cargo = Some(cargo);
+        let is_self_testing = self.is_self_testing()?;
+        self.node_mod_dist = cfg
+            .node
+            .clone()
+            .and_then(|s| PathBuf::from(s).parent().map(|p| p.to_path_buf()))
+            .or_else(|| {
+                Some(if is_self_testing {
+                    PathBuf::from(format!("./target/selftests/{}", Uuid::new_v4()))
+                } else {
+                    PathBuf::from("./dist")
+                })
+            });
+        let dist = self.node_mod_dist.clone().unwrap_or_default();
+        self.js_file = cfg
+            .js
+            .map(PathBuf::from)
+            .unwrap_or_else(|| dist.join("lib.js"));
+        self.dts_file = cfg
+            .dts
+            .map(PathBuf::from)
+            .unwrap_or_else(|| dist.join("lib.d.ts"));
+        self.package_file = cfg
+            .package
+            .map(PathBuf::from)
+            .unwrap_or_else(|| dist.join("package.json"));
+        self.json_schema = cfg.json_schema.map(PathBuf::from);
+        self.node_mod_filename = cfg.node.and_then(|s| {
+            PathBuf::from(s)
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string())
+        });
+        if let Some(snake_case_naming) = cfg.snake_case_naming {
+            for case in snake_case_naming.split(',') {
+                let condition: SnakeCaseNaming = case.try_into()?;
+                if !self.snake_case_naming.contains(&condition) {
+                    self.snake_case_naming.insert(condition);
+                }
+            }
+        }
+        self.exception_suppression = cfg.exception_suppression;
+        self.int_over_32_as_big_int = cfg.int_over_32_as_big_int;
+        self.type_map = cfg.type_map;
+        self.enum_representation = cfg.enum_representation;
+        self.skip_private = cfg.skip_private;
+        self.deferred_generation = cfg.deferred_generation;
+        self.force_overwrite = cfg.force_overwrite;
+        self.zod = cfg.zod;
+        self.type_guards = cfg.type_guards;
+        self.validate_args = cfg.validate_args;
+        self.import_extension = cfg.import_extension.unwrap_or_default();
+        self.module_format = if let Some(module_format) = cfg.module_format {
+            module_format.as_str().try_into()?
+        } else {
+            ModuleFormat::default()
+        };
+        self.backend = if let Some(backend) = cfg.backend {
+            backend.as_str().try_into()?
+        } else {
+            Backend::default()
+        };
+        let style = cfg.style;
+        self.style = Style {
+            indent: match style.indent_style.as_deref() {
+                None | Some("spaces") => Indent::Spaces(
+                    style
+                        .indent_width
+                        .map(|w| {
+                            usize::try_from(w).map_err(|_| {
+                                E::InvalidConfiguration(format!("Invalid indent_width: {w}"))
+                            })
+                        })
+                        .transpose()?
+                        .unwrap_or(4),
+                ),
+                Some("tabs") => Indent::Tabs,
+                Some(other) => {
+                    return Err(E::InvalidConfiguration(format!(
+                        "Unknown option for indent_style option: \"{other}\""
+                    )))
+                }
+            },
+            quotes: if let Some(quotes) = style.quotes {
+                quotes.as_str().try_into()?
+            } else {
+                Quotes::default()
+            },
+            semicolons: style.semicolons.unwrap_or(true),
+            line_endings: if let Some(line_endings) = style.line_endings {
+                line_endings.as_str().try_into()?
+            } else {
+                LineEndings::default()
+            },
+        };
+        Ok(())
+    }
+
+    pub fn get_cargo(&self) -> &Table {
+        self.cargo.as_ref().expect("Cargo.toml should be available")
+    }
+
+    pub fn is_self_testing(&self) -> Result<bool, E> {
+        Ok(self.package_name()? == *"tslink")
+    }
+
+    /// Returns the name of the package from `[package]` of `Cargo.toml`.
+    pub fn package_name(&self) -> Result<String, E> {
+        let package = self
+            .get_cargo()
+            .get("package")
+            .ok_or(E::Other(String::from(
+                "Fail to find [package] in Cargo.toml",
+            )))?;
+        value(package, "name")
+    }
+
+    pub fn rename_field(&self, origin: &str) -> String {
+        if self.snake_case_naming.contains(&SnakeCaseNaming::Fields) {
+            origin.to_case(Case::Camel)
+        } else {
+            origin.to_owned()
+        }
+    }
+
+    pub fn rename_method(&self, origin: &str) -> String {
+        if self.snake_case_naming.contains(&SnakeCaseNaming::Methods) {
+            origin.to_case(Case::Camel)
+        } else {
+            origin.to_owned()
+        }
+    }
+
+    pub fn overwrite_reftype<S: AsRef<str>>(&self, origin: S) -> String {
+        self.type_map
+            .get(origin.as_ref())
+            .map(|s| s.to_owned())
+            .unwrap_or(origin.as_ref().to_owned())
+    }
+}
+
+pub fn setup() -> Result<(), E> {
+    if CONFIG
+        .read()
+        .map_err(|e| E::AccessError(e.to_string()))?
+        .inited
+    {
+        return Ok(());
+    }
+    init(
+        &manifest_dir()?,
+        env::var_os(TSLINK_BUILD_ENV).is_some_and(|v| {
+            ["1", "true", "on"].contains(&v.to_string_lossy().to_lowercase().trim())
+        }),
+    )
+}
+
+/// Returns the folder of the crate's `Cargo.toml` (`CARGO_MANIFEST_DIR` or the current folder).
+pub fn manifest_dir() -> Result<PathBuf, E> {
+    Ok(match env::var("CARGO_MANIFEST_DIR") {
+        Ok(manifest_dir) => PathBuf::from(manifest_dir),
+        Err(_) => std::env::current_dir()?,
+    })
+}
+
+/// Reads configuration from `Cargo.toml` in the given folder; overwrites the current configuration.
+pub fn init(root: &Path, io_allowed: bool) -> Result<(), E> {
+    let cargo = root.join("Cargo.toml");
+    if !cargo.exists() {
+        return Err(E::FileNotFound(format!(
+            "Cargo.toml isn't found in {}",
+            root.to_string_lossy()
+        )));
+    }
+    let mut config = CONFIG.write().map_err(|e| E::AccessError(e.to_string()))?;
+    config.overwrite(toml::from_str(&fs::read_to_string(cargo)?)?, io_allowed)?;
+    config.manifest_dir = root.to_path_buf();
+    Ok(())
+}
+
+/// Sets the folder, which relative paths of artifacts are resolved from. A relative folder is
+/// resolved from the current folder.
+pub fn set_output_dir(output_dir: Option<PathBuf>) -> Result<(), E> {
+    let output_dir = match output_dir {
+        Some(output_dir) => Some(env::current_dir()?.join(output_dir)),
+        None => None,
+    };
+    CONFIG
+        .write()
+        .map_err(|e| E::AccessError(e.to_string()))?
+        .output_dir = output_dir;
+    Ok(())
+}
+
+/// Returns the folder, which relative paths of artifacts are resolved from: the configured
+/// output folder or the current folder.
+pub fn output_dir() -> Result<PathBuf, E> {
+    let output_dir = CONFIG
+        .read()
+        .map_err(|e| E::AccessError(e.to_string()))?
+        .output_dir
+        .clone();
+    Ok(match output_dir {
+        Some(output_dir) => output_dir,
+        None => env::current_dir()?,
+    })
+}
+
+pub fn get() -> Result<Config, E> {
+    Ok(CONFIG
+        .read()
+        .map_err(|e| E::AccessError(e.to_string()))?
+        .clone())
+}
@@ -1,11 +1,12 @@
 use crate::context::Target;
+use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, fmt, path::PathBuf};
 
 /// Describes macro-level attributes that control TypeScript code generation behavior.
 ///
 /// Each variant corresponds to a specific directive or modifier used in procedural macros
 /// to guide how Rust types and functions should be translated into TypeScript declarations or JavaScript wrappers.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Input {
     /// Specifies a list of field names to ignore during code generation.
     Ignore(Vec<String>),
@@ -48,6 +49,21 @@ pub enum Input {
     /// Functions and method calls will be wrapped in `try { ... } catch (e) {}` blocks,
     /// and their output type will be adjusted to `T | Error`.
     ExceptionSuppression,
+
+    /// Marks a field as read-only: `readonly` in declarations and getter-only in JS class wrapper.
+    Readonly,
+
+    /// Exposes a method without arguments as a read-only property (getter) of the class.
+    Getter,
+
+    /// Exposes a method with a single argument as a setter of the given property.
+    Setter(String),
+
+    /// Forces a non-`pub` field or method to be included in the generated output.
+    Include,
+
+    /// Excludes the item from the `index.ts` barrel of its module folder.
+    NoIndex,
 }
 
 impl TryFrom<&str> for Input {
@@ -73,6 +89,16 @@ impl TryFrom<&str> for Input {
             Ok(Input::Module(String::new()))
         } else if Input::ExceptionSuppression.to_string() == value {
             Ok(Input::ExceptionSuppression)
+        } else if Input::Readonly.to_string() == value {
+            Ok(Input::Readonly)
+        } else if Input::Getter.to_string() == value {
+            Ok(Input::Getter)
+        } else if Input::Setter(String::new()).to_string() == value {
+            Ok(Input::Setter(String::new()))
+        } else if Input::Include.to_string() == value {
+            Ok(Input::Include)
+        } else if Input::NoIndex.to_string() == value {
+            Ok(Input::NoIndex)
         } else {
             Err(format!("Unknown attribute \"{value}\""))
         }
@@ -96,6 +122,11 @@ impl fmt::Display for Input {
                 Self::Binding(..) => "**THIS_IS_RESERVED_KEY_WORD**",
                 Self::Class => "class",
                 Self::ExceptionSuppression => "exception_suppression",
+                Self::Readonly => "readonly",
+                Self::Getter => "getter",
+                Self::Setter(..) => "setter",
+                Self::Include => "include",
+                Self::NoIndex => "no_index",
             }
         )
     }
@@ -8,8 +8,9 @@ use crate::{
 };
 use convert_case::{Case, Casing};
 use input::Input;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::BTreeMap,
     convert::{From, TryFrom},
     ops::Deref,
     path::PathBuf,
@@ -21,6 +22,9 @@ use syn::{
 };
 pub use target::Target;
 
+/// Name of the attribute macro (`#[tslink]`).
+const ATTR_NAME: &str = "tslink";
+
 /// Holds the contextual information associated with a macro invocation.
 ///
 /// `Context` provides metadata required during code generation,
@@ -30,7 +34,7 @@ pub use target::Target;
 /// It is attached to every entity (`Referred`, `Field`, `Func`, etc.)
 /// that participates in the TypeScript code generation process,
 /// and is used to propagate configuration and behavior settings.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Context {
     /// List of macro inputs (`#[tslink(...)]`) that define generation rules
     /// such as renaming, ignoring, binding modes, etc.
@@ -46,7 +50,7 @@ pub struct Context {
 
     /// Mapping of generic type identifiers to their resolved [`Nature`].
     /// This enables handling of type aliases and resolving bindings like `T: SomeType` for accurate generation.
-    pub generics: HashMap<String, Nature>,
+    pub generics: BTreeMap<String, Nature>,
 }
 
 impl Context {
@@ -65,7 +69,7 @@ impl Context {
             inputs,
             targets,
             parent: None,
-            generics: HashMap::new(),
+            generics: BTreeMap::new(),
         }
     }
 
@@ -79,8 +83,7 @@ impl Context {
                     // No attributes
                     continue;
                 }
-                let attr_name = attr.path().segments.last().unwrap().ident.to_string();
-                if attr_name != env!("CARGO_PKG_NAME") {
+                if !Context::is_own_attr(attr) {
                     continue;
                 }
                 return attr
@@ -91,6 +94,14 @@ impl Context {
         Ok(Self::default())
     }
 
+    /// Returns `true` if the given attribute is `#[tslink]` or `#[tslink(...)]`.
+    pub fn is_own_attr(attr: &Attribute) -> bool {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == ATTR_NAME)
+    }
+
     /// Retrieves the optional module name specified in the attributes.
     pub fn get_module(&self) -> Option<String> {
         self.inputs.iter().find_map(|inp| {
@@ -177,6 +188,37 @@ impl Context {
         self.inputs.iter().any(|i| matches!(i, Input::Constructor))
     }
 
+    /// Returns `true` if the field or method is marked with `#[tslink(include)]`.
+    pub fn as_include(&self) -> bool {
+        self.inputs.iter().any(|i| matches!(i, Input::Include))
+    }
+
+    /// Returns `true` if the item is marked with `#[tslink(no_index)]`.
+    pub fn as_no_index(&self) -> bool {
+        self.inputs.iter().any(|i| matches!(i, Input::NoIndex))
+    }
+
+    /// Returns `true` if the field is marked with `#[tslink(readonly)]`.
+    pub fn as_readonly(&self) -> bool {
+        self.inputs.iter().any(|i| matches!(i, Input::Readonly))
+    }
+
+    /// Returns `true` if the method is marked with `#[tslink(getter)]`.
+    pub fn as_getter(&self) -> bool {
+        self.inputs.iter().any(|i| matches!(i, Input::Getter))
+    }
+
+    /// Retrieves the name of the property, which is set by the method marked with `#[tslink(setter = "name")]`.
+    pub fn get_setter(&self) -> Option<String> {
+        self.inputs.iter().find_map(|inp| {
+            if let Input::Setter(property) = inp {
+                Some(property.to_owned())
+            } else {
+                None
+            }
+        })
+    }
+
     /// Returns `true` if exception suppression is enabled either via macro attribute or global config.
     ///
     /// This mode wraps JS calls in `try/catch` and changes the output type to `T | Error`.
@@ -298,6 +340,9 @@ impl Parse for Context {
     ///   #[tslink(ignore)]
     ///   #[tslink(interface)]
     ///   #[tslink(exception_suppression)]
+    ///   #[tslink(readonly)]
+    ///   #[tslink(include)]
+    ///   #[tslink(getter)]
     ///   ```
     ///
     /// - Key-value assignments:
@@ -307,6 +352,7 @@ impl Parse for Context {
     ///   #[tslink(target = "out.d.ts;other.ts")]
     ///   #[tslink(ignore = "field1;field2")]
     ///   #[tslink(result = "json", error = "json")]
+    ///   #[tslink(setter = "field")]
     ///   ```
     ///
     /// Any unrecognized key-value assignment is interpreted as a **binding** —
@@ -337,14 +383,20 @@ impl Parse for Context {
                                 Ok(input) => match input {
                                     Input::Rename(..) => Some(Input::Rename(value)),
                                     Input::Module(..) => Some(Input::Module(value)),
+                                    Input::Setter(..) => Some(Input::Setter(value)),
                                     Input::Target(..) => {
                                         let mut targets: Vec<(Target, PathBuf)> = vec![];
                                         for s in value.split(';') {
                                             let path = PathBuf::from(s.trim());
-                                            if let Some(ext) = path.extension() {
-                                                match Target::try_from(
-                                                    ext.to_string_lossy().to_string().as_str(),
-                                                ) {
+                                            // Extension of "*.d.ts" is "ts"
+                                            let ext = if s.trim().ends_with(".d.ts") {
+                                                Some(Target::DTs.to_string())
+                                            } else {
+                                                path.extension()
+                                                    .map(|ext| ext.to_string_lossy().to_string())
+                                            };
+                                            if let Some(ext) = ext {
+                                                match Target::try_from(ext.as_str()) {
                                                     Ok(t) => targets.push((t, path)),
                                                     Err(e) => {
                                                         return Err(syn::Error::new(
@@ -406,6 +458,10 @@ impl Parse for Context {
                                     | Input::Class
                                     | Input::Interface
                                     | Input::ExceptionSuppression
+                                    | Input::Readonly
+                                    | Input::Getter
+                                    | Input::Include
+                                    | Input::NoIndex
                                     | Input::Constructor => input,
                                     _ => {
                                         return Err(syn::Error::new(
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, fmt};
 
 /// Represents a code generation target for TypeScript or JavaScript output.
@@ -9,7 +10,7 @@ use std::{convert::TryFrom, fmt};
 /// - `Ts` – Generate a TypeScript source file (`.ts`).
 /// - `DTs` – Generate a TypeScript declaration file (`.d.ts`).
 /// - `Js` – Generate a JavaScript implementation file (`.js`).
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Target {
     Ts,
     DTs,
@@ -11,6 +11,8 @@ pub enum E {
     PasringToml(#[from] toml::de::Error),
     #[error("IO error")]
     IO(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
     #[error("LexError error")]
     LexError(#[from] proc_macro2::LexError),
     #[error("Not supported. Try to ignore it with #[tslink(ignore)]: {0}")]
@@ -25,12 +27,12 @@ pub enum E {
     Parsing(String),
     #[error("Entity already has been read: {0}")]
     EntityExist(String),
+    #[error("File has been modified manually: {0}. Restore it, remove it or set force_overwrite = true in [tslink] of Cargo.toml")]
+    ModifiedManually(String),
     #[error("File not found: {0}")]
     FileNotFound(String),
     #[error("Compiler error")]
     Compiler(syn::Error),
-    #[error("Fail to access to imports list")]
-    ImportsListAccess,
     #[error("{0}")]
     Other(String),
 }
@@ -0,0 +1,322 @@
+use crate::{
+    config,
+    context::Context,
+    error::E,
+    interpreter::{self, absolute},
+    nature::Natures,
+    reader, registry, JOURNAL,
+};
+use proc_macro2::{Span, TokenStream};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use syn::{spanned::Spanned, Attribute, Expr, Item, Lit, Meta};
+use thiserror::Error;
+
+/// Error of [`Generator`].
+///
+/// Errors related to the sources are reported in the format of compiler diagnostics
+/// (`file:line:column: message`), so editors and CI can point to the place of the error.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Error in the sources of the crate: file, line, column (both starting from 1) and message.
+    #[error("{}:{1}:{2}: {3}", .0.display())]
+    Source(PathBuf, usize, usize, String),
+    /// Error of configuration, reading of sources or writing of artifacts.
+    #[error("{0}")]
+    Other(String),
+}
+
+impl Error {
+    fn at(file: &Path, span: Span, msg: String) -> Self {
+        let start = span.start();
+        Error::Source(file.to_owned(), start.line, start.column + 1, msg)
+    }
+
+    fn syn(file: &Path, err: syn::Error) -> Self {
+        Error::at(file, err.span(), err.to_string())
+    }
+}
+
+impl From<E> for Error {
+    fn from(err: E) -> Self {
+        Error::Other(err.to_string())
+    }
+}
+
+/// Generates artifacts (`*.js`, `*.d.ts`, `*.ts`, `package.json`) for a crate by parsing its sources.
+///
+/// Modules of the crate are walked starting from the crate root (`mod name;` is resolved the way
+/// rustc does it, including `#[path = "..."]`), items annotated with `#[tslink]` are read and all
+/// artifacts are written once. Modules under `#[cfg(test)]` are skipped.
+///
+/// ```ignore
+/// // build.rs
+/// fn main() {
+///     println!("cargo:rerun-if-changed=src");
+///     if let Err(err) = tslink_build::Generator::new().crate_root("src/lib.rs").run() {
+///         panic!("{err}");
+///     }
+/// }
+/// ```
+///
+/// Paths of artifacts in the configuration are relative to the current folder, which is the folder
+/// of the crate for build scripts and tests, or to the folder set with [`Generator::output_dir`].
+pub struct Generator {
+    crate_root: PathBuf,
+    manifest_dir: Option<PathBuf>,
+    output_dir: Option<PathBuf>,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Generator {
+    pub fn new() -> Self {
+        Self {
+            crate_root: PathBuf::from("src/lib.rs"),
+            manifest_dir: None,
+            output_dir: None,
+        }
+    }
+
+    /// Sets the root file of the crate (`src/lib.rs` by default). A relative path is resolved
+    /// from the folder of `Cargo.toml`.
+    pub fn crate_root<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.crate_root = path.as_ref().to_path_buf();
+        self
+    }
+
+    /// Sets the folder of the crate's `Cargo.toml` (by default `CARGO_MANIFEST_DIR` or the current folder).
+    pub fn manifest_dir<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.manifest_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the folder, which relative paths of artifacts are resolved from (by default the current folder).
+    pub fn output_dir<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.output_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Reads the crate and writes all artifacts.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration cannot be read, the sources cannot be parsed,
+    /// an entity cannot be converted or artifacts cannot be written.
+    pub fn run(&self) -> Result<(), Error> {
+        self.generate(false)
+    }
+
+    /// Reads the crate and compares existing artifacts with artifacts generated from the current
+    /// sources; nothing is written. Returns paths of files, which are missing or differ.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration cannot be read, the sources cannot be parsed or
+    /// an entity cannot be converted.
+    pub fn check(&self) -> Result<Vec<PathBuf>, Error> {
+        self.generate(true)?;
+        Ok(JOURNAL
+            .read()
+            .map_err(|e| Error::Other(e.to_string()))?
+            .changed
+            .clone())
+    }
+
+    /// Removes all files generated for the crate: files recorded by previous runs (and builds)
+    /// and files, which would be generated from the current sources. Returns paths of removed files.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration cannot be read or a file cannot be removed.
+    pub fn clean(&self) -> Result<Vec<PathBuf>, Error> {
+        self.setup()?;
+        // Sources could be broken; in this case only recorded files are removed
+        let mut files = if self.generate(true).is_ok() {
+            JOURNAL
+                .read()
+                .map_err(|e| Error::Other(e.to_string()))?
+                .files
+                .iter()
+                .map(|f| absolute(f))
+                .collect::<Result<Vec<PathBuf>, E>>()?
+        } else {
+            vec![]
+        };
+        for file in registry::remove()? {
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+        let mut removed = vec![];
+        for file in files.into_iter().filter(|f| f.exists()) {
+            fs::remove_file(&file)
+                .map_err(|e| Error::Other(format!("Fail to remove {}: {e}", file.display())))?;
+            // Folders of artifacts are removed if nothing else is there
+            if let Some(dir) = file.parent() {
+                let _ = fs::remove_dir(dir);
+            }
+            removed.push(file);
+        }
+        Ok(removed)
+    }
+
+    fn generate(&self, check: bool) -> Result<(), Error> {
+        {
+            let mut journal = JOURNAL.write().map_err(|e| Error::Other(e.to_string()))?;
+            journal.check = check;
+            journal.files.clear();
+            journal.changed.clear();
+        }
+        let manifest_dir = self.setup()?;
+        let mut cfg = config::get()?;
+        // Files are generated once, after all entities are read
+        cfg.deferred_generation = true;
+        let root = manifest_dir.join(&self.crate_root);
+        let dir = root.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+        let mut natures = Natures::new();
+        read_file(&root, &dir, &mut natures, &cfg)?;
+        registry::sync(&natures, true).and_then(|natures| interpreter::generate(&natures, &cfg))?;
+        Ok(())
+    }
+
+    /// Reads the configuration from `Cargo.toml`; returns the folder of `Cargo.toml`.
+    fn setup(&self) -> Result<PathBuf, Error> {
+        let manifest_dir = if let Some(manifest_dir) = self.manifest_dir.as_ref() {
+            manifest_dir.to_owned()
+        } else {
+            config::manifest_dir()?
+        };
+        config::init(&manifest_dir, true)?;
+        config::set_output_dir(self.output_dir.clone())?;
+        Ok(manifest_dir)
+    }
+}
+
+/// Reads the file of a module; `dir` is the folder of nested modules of this module.
+fn read_file(
+    file: &Path,
+    dir: &Path,
+    natures: &mut Natures,
+    cfg: &config::Config,
+) -> Result<(), Error> {
+    let content = fs::read_to_string(file)
+        .map_err(|e| Error::Other(format!("Fail to read {}: {e}", file.display())))?;
+    let ast = syn::parse_file(&content).map_err(|e| Error::syn(file, e))?;
+    natures.set_source(Some(file.to_owned()));
+    read_items(ast.items, file, dir, natures, cfg)
+}
+
+fn read_items(
+    items: Vec<Item>,
+    file: &Path,
+    dir: &Path,
+    natures: &mut Natures,
+    cfg: &config::Config,
+) -> Result<(), Error> {
+    for item in items {
+        if let Item::Mod(item_mod) = item {
+            if is_cfg_test(&item_mod.attrs) {
+                continue;
+            }
+            let name = item_mod.ident.to_string();
+            let path = get_path_attr(&item_mod.attrs);
+            if let Some((_, items)) = item_mod.content {
+                let dir = dir.join(path.unwrap_or(name));
+                read_items(items, file, &dir, natures, cfg)?;
+            } else {
+                let (mod_file, mod_dir) = if let Some(path) = path {
+                    // Path is relative to the folder of the current file
+                    let mod_file = file
+                        .parent()
+                        .map(|p| p.join(&path))
+                        .unwrap_or(PathBuf::from(path));
+                    let mod_dir = mod_file.parent().map(|p| p.to_path_buf());
+                    (mod_file, mod_dir.unwrap_or_default())
+                } else if dir.join(format!("{name}.rs")).exists() {
+                    (dir.join(format!("{name}.rs")), dir.join(&name))
+                } else {
+                    (dir.join(&name).join("mod.rs"), dir.join(&name))
+                };
+                if !mod_file.exists() {
+                    return Err(Error::at(
+                        file,
+                        item_mod.ident.span(),
+                        format!("Fail to find file of module \"{name}\""),
+                    ));
+                }
+                read_file(&mod_file, &mod_dir, natures, cfg)?;
+                natures.set_source(Some(file.to_owned()));
+            }
+        } else {
+            read_item(item, file, natures, cfg)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads the item if it's annotated with `#[tslink]`.
+fn read_item(
+    mut item: Item,
+    file: &Path,
+    natures: &mut Natures,
+    cfg: &config::Config,
+) -> Result<(), Error> {
+    let span = item.span();
+    let attrs = match &mut item {
+        Item::Struct(item) => &mut item.attrs,
+        Item::Enum(item) => &mut item.attrs,
+        Item::Fn(item) => &mut item.attrs,
+        Item::Impl(item) => &mut item.attrs,
+        Item::Const(item) => &mut item.attrs,
+        _ => return Ok(()),
+    };
+    let attr = if let Some(pos) = attrs.iter().position(Context::is_own_attr) {
+        // The macro doesn't see its own attribute; remove it the same way
+        attrs.remove(pos)
+    } else {
+        return Ok(());
+    };
+    let args = if let Meta::List(list) = &attr.meta {
+        list.tokens.clone()
+    } else {
+        TokenStream::new()
+    };
+    let context: Context = syn::parse2(args).map_err(|e| Error::syn(file, e))?;
+    if context.ignore_self() {
+        return Ok(());
+    }
+    reader::read(&mut item, natures, context, cfg)
+        .map(|_| ())
+        .map_err(|e| match e {
+            E::Compiler(err) => Error::syn(file, err),
+            e => Error::at(file, span, e.to_string()),
+        })
+}
+
+fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "test")
+    })
+}
+
+fn get_path_attr(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if let Meta::NameValue(meta) = &attr.meta {
+            if meta.path.is_ident("path") {
+                if let Expr::Lit(expr) = &meta.value {
+                    if let Lit::Str(lit) = &expr.lit {
+                        return Some(lit.value());
+                    }
+                }
+            }
+        }
+        None
+    })
+}
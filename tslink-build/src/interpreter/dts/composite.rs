@@ -4,18 +4,10 @@ use crate::{
     interpreter::Offset,
     nature::{Composite, Nature, Natures, Referred, TypeAsString},
 };
-use std::{
-    fs::File,
-    io::{BufWriter, Write},
-};
+use std::io::Write;
 
 impl Interpreter for Composite {
-    fn reference(
-        &self,
-        natures: &Natures,
-        buf: &mut BufWriter<File>,
-        offset: Offset,
-    ) -> Result<(), E> {
+    fn reference(&self, natures: &Natures, buf: &mut Vec<u8>, offset: Offset) -> Result<(), E> {
         match self {
             Self::Array(ty) => {
                 ty.reference(natures, buf, offset)?;
@@ -44,7 +36,7 @@ impl Interpreter for Composite {
                     )));
                 }
             }
-            Self::Func(_, args, out, asyncness, constructor) => {
+            Self::Func(_, args, out, asyncness, constructor, _) => {
                 buf.write_all("(".as_bytes())?;
                 let mut generic = false;
                 for (i, nature) in args.iter().enumerate() {
@@ -0,0 +1,152 @@
+mod composite;
+mod primitive;
+mod refered;
+
+use crate::{
+    config,
+    context::Target,
+    error::E,
+    interpreter::{absolute, guard, specifier, ts::Import, Emitter, Offset, Output},
+    nature::{Nature, Natures, TypeAsString},
+};
+use std::{collections::BTreeSet, path::Path};
+
+/// Trait for describing how a given type (`Nature`) is declared or referenced
+/// within a TypeScript declaration file (`*.d.ts`).
+///
+/// This trait allows recursive traversal and structured rendering of types.
+/// Its implementation is simplified here, as `*.d.ts` generation doesn't need imports.
+pub trait Interpreter {
+    /// Writes the full type declaration, usually at the top level.
+    fn declaration(
+        &self,
+        _natures: &Natures,
+        _buf: &mut Vec<u8>,
+        _offset: Offset,
+    ) -> Result<(), E> {
+        Ok(())
+    }
+
+    /// Writes a reference to the type (e.g., inside a struct or function).
+    fn reference(&self, _natures: &Natures, _buf: &mut Vec<u8>, _offset: Offset) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+impl Interpreter for Nature {
+    /// Delegates declaration rendering to the specific variant:
+    /// - `Primitive`, `Composite`, or `Referred`.
+    fn declaration(&self, natures: &Natures, buf: &mut Vec<u8>, offset: Offset) -> Result<(), E> {
+        match self {
+            Self::Primitive(primitive) => primitive.declaration(natures, buf, offset),
+            Self::Composite(composite) => composite.declaration(natures, buf, offset),
+            Self::Referred(refered) => refered.declaration(natures, buf, offset),
+        }
+    }
+
+    /// Same logic for references: recursively resolve how a nested type should be printed.
+    fn reference(&self, natures: &Natures, buf: &mut Vec<u8>, offset: Offset) -> Result<(), E> {
+        match self {
+            Self::Primitive(primitive) => primitive.reference(natures, buf, offset),
+            Self::Composite(composite) => composite.reference(natures, buf, offset),
+            Self::Referred(refered) => refered.reference(natures, buf, offset),
+        }
+    }
+}
+
+/// Renders the full type declaration into `lib.d.ts`.
+///
+/// This is the entry point for generating global type definitions. The declaration is
+/// appended to the in-memory content of the declaration file (`config.dts_file`, by default
+/// `lib.d.ts` in the folder of the node module); the file is written later by `Output`.
+///
+/// # Arguments
+/// - `entity`: The entity to declare.
+/// - `natures`: Shared type registry, used for resolving nested types.
+/// - `output`: In-memory content of generated files.
+///
+/// # Errors
+/// Returns an error if rendering fails or the output path isn't configured.
+pub fn write(entity: &Nature, natures: &Natures, output: &mut Output) -> Result<(), E> {
+    let emitter = Emitter::get()?;
+    let mut buf = Vec::new();
+    entity.declaration(natures, &mut buf, emitter.offset())?;
+    let dts_file = config::get()?.dts_file;
+    output.append(&dts_file, String::from_utf8_lossy(&buf));
+    output.append(&dts_file, guards(entity, &emitter)?);
+    Ok(())
+}
+
+/// Returns declarations of the type guard and the assertion of the entity, if the entity
+/// has them and `type_guards = true`.
+fn guards(entity: &Nature, emitter: &Emitter) -> Result<String, E> {
+    if !config::get()?.type_guards || !guard::has_guard(entity) {
+        return Ok(String::new());
+    }
+    Ok(guard::dts_declaration(&entity.type_as_string()?, emitter))
+}
+
+/// Renders the declaration of the entity into its own declaration file
+/// (`#[tslink(target = "./file.d.ts")]`).
+///
+/// Entities referred by the declaration are imported with `import type` from their own
+/// declaration file or, if there is none, from `lib.d.ts` (`config.dts_file`), when the
+/// node module is configured.
+///
+/// # Arguments
+/// - `entity`: The entity to declare.
+/// - `natures`: Shared type registry, used for resolving nested types.
+/// - `path`: The declaration file of the entity.
+/// - `output`: In-memory content of generated files.
+///
+/// # Errors
+/// Returns an error if rendering fails or paths cannot be resolved.
+pub fn write_item(
+    entity: &Nature,
+    natures: &Natures,
+    path: &Path,
+    output: &mut Output,
+) -> Result<(), E> {
+    let config = config::get()?;
+    let emitter = Emitter::new(config.style);
+    let mut names = BTreeSet::new();
+    entity.refs(&mut names);
+    let location = path.parent().unwrap_or(path);
+    let rendered = output.get(path).unwrap_or_default().to_owned();
+    let mut imports = BTreeSet::new();
+    for name in names.iter().filter(|name| natures.contains(name)) {
+        let source = match natures.get_target_of(name, &Target::DTs) {
+            Some(source) => source,
+            None if config.node_mod_filename.is_some() => config.dts_file.clone(),
+            None => continue,
+        };
+        if absolute(&source)? == absolute(path)? {
+            continue;
+        }
+        // "module.d.ts" is imported as "./module" (or "./module.js" with import_extension)
+        let source = source.to_string_lossy();
+        let import = Import {
+            entity: name.to_owned(),
+            specifier: format!(
+                "{}{}",
+                specifier(
+                    location,
+                    Path::new(source.strip_suffix(".d.ts").unwrap_or(&source))
+                )?,
+                config.import_extension
+            ),
+            type_only: true,
+        };
+        if !rendered.contains(&import.render(&emitter)) {
+            imports.insert(import);
+        }
+    }
+    let mut buf = Vec::new();
+    entity.declaration(natures, &mut buf, emitter.offset())?;
+    for import in imports.iter() {
+        output.append(path, format!("{}\n", import.render(&emitter)));
+    }
+    output.append(path, String::from_utf8_lossy(&buf));
+    output.append(path, guards(entity, &emitter)?);
+    Ok(())
+}
@@ -4,18 +4,10 @@ use crate::{
     interpreter::Offset,
     nature::{Natures, Primitive},
 };
-use std::{
-    fs::File,
-    io::{BufWriter, Write},
-};
+use std::io::Write;
 
 impl Interpreter for Primitive {
-    fn reference(
-        &self,
-        _natures: &Natures,
-        buf: &mut BufWriter<File>,
-        _offset: Offset,
-    ) -> Result<(), E> {
+    fn reference(&self, _natures: &Natures, buf: &mut Vec<u8>, _offset: Offset) -> Result<(), E> {
         Ok(buf.write_all(
             match self {
                 Self::Number(..) => "number",
@@ -5,21 +5,13 @@ use crate::{
     interpreter::Offset,
     nature::{Composite, Nature, Natures, Referred},
 };
-use std::{
-    fs::File,
-    io::{BufWriter, Write},
-    ops::Deref,
-};
+use std::{io::Write, ops::Deref};
 
 impl Interpreter for Referred {
-    fn declaration(
-        &self,
-        natures: &Natures,
-        buf: &mut BufWriter<File>,
-        offset: Offset,
-    ) -> Result<(), E> {
+    fn declaration(&self, natures: &Natures, buf: &mut Vec<u8>, offset: Offset) -> Result<(), E> {
+        let emitter = offset.emitter();
         match self {
-            Referred::Enum(name, _context, variants, repres) => {
+            Referred::Enum(name, _context, variants, repres, methods) => {
                 let flat = Referred::is_flat_varians(variants)?;
                 if flat {
                     buf.write_all(format!("{offset}export enum {name} {{\n",).as_bytes())?;
@@ -36,7 +28,7 @@ impl Interpreter for Referred {
                             )?;
                             for variant in variants.iter() {
                                 variant.declaration(natures, buf, offset.inc())?;
-                                buf.write_all(";\n".as_bytes())?;
+                                buf.write_all(format!("{}\n", emitter.semi()).as_bytes())?;
                             }
                             buf.write_all(format!("{offset}}}\n",).as_bytes())?;
                         }
@@ -48,10 +40,54 @@ impl Interpreter for Referred {
                                     if n == variants.len() - 1 { "" } else { " |\n" }.as_bytes(),
                                 )?;
                             }
-                            buf.write_all(format!("{offset};\n",).as_bytes())?;
+                            buf.write_all(format!("{}\n", emitter.semi()).as_bytes())?;
                         }
                     }
                 }
+                // Methods of enum are declared as functions of namespace with the same name
+                if !methods.is_empty() {
+                    buf.write_all(
+                        format!("{offset}export declare namespace {name} {{\n").as_bytes(),
+                    )?;
+                    for method in methods.iter() {
+                        if let Nature::Referred(Referred::Field(method_name, context, func, _)) =
+                            method
+                        {
+                            if let Nature::Composite(Composite::Func(_, args, out, asyncness, ..)) =
+                                func.deref()
+                            {
+                                buf.write_all(
+                                    format!(
+                                        "{}export function {}(",
+                                        offset.inc(),
+                                        context.rename_method(method_name)?
+                                    )
+                                    .as_bytes(),
+                                )?;
+                                for (i, ty) in args.iter().enumerate() {
+                                    ty.declaration(natures, buf, emitter.offset())?;
+                                    if i < args.len() - 1 {
+                                        buf.write_all(", ".as_bytes())?;
+                                    }
+                                }
+                                buf.write_all("): ".as_bytes())?;
+                                if *asyncness {
+                                    buf.write_all("Promise<".as_bytes())?;
+                                }
+                                if let Some(out) = out {
+                                    out.reference(natures, buf, offset.inc())?;
+                                } else {
+                                    buf.write_all("void".as_bytes())?;
+                                }
+                                if *asyncness {
+                                    buf.write_all(">".as_bytes())?;
+                                }
+                                buf.write_all(format!("{}\n", emitter.semi()).as_bytes())?;
+                            }
+                        }
+                    }
+                    buf.write_all(format!("{offset}}}\n").as_bytes())?;
+                }
             }
             Referred::EnumVariant(name, _context, fields, flat, repres) => {
                 let named = fields
@@ -69,7 +105,7 @@ impl Interpreter for Referred {
                                 buf.write_all(format!("{offset}{{ {name}: null }}").as_bytes())?;
                             }
                             EnumRepresentation::DiscriminatedUnion => {
-                                buf.write_all(format!("{offset}\"{name}\"").as_bytes())?;
+                                buf.write_all(format!("{offset}{}", emitter.str(name)).as_bytes())?;
                             }
                         }
                     }
@@ -171,7 +207,14 @@ impl Interpreter for Referred {
                             },
                         )?;
                         if i < fields.len() - 1 {
-                            buf.write_all(if named { ";\n" } else { "," }.as_bytes())?;
+                            buf.write_all(
+                                if named {
+                                    format!("{}\n", emitter.semi())
+                                } else {
+                                    ",".to_owned()
+                                }
+                                .as_bytes(),
+                            )?;
                         }
                     }
                     buf.write_all(
@@ -210,7 +253,7 @@ impl Interpreter for Referred {
                 buf.write_all(format!("{}: ", context.rename_field(name)?).as_bytes())?
             }
             Referred::Func(name, context, func) => {
-                if let Nature::Composite(Composite::Func(_, args, out, asyncness, constructor)) =
+                if let Nature::Composite(Composite::Func(_, args, out, asyncness, constructor, _)) =
                     func.deref()
                 {
                     if *constructor {
@@ -221,7 +264,7 @@ impl Interpreter for Referred {
                         format!("{offset}export declare function {renamed}(",).as_bytes(),
                     )?;
                     for (i, ty) in args.iter().enumerate() {
-                        ty.declaration(natures, buf, Offset::new())?;
+                        ty.declaration(natures, buf, emitter.offset())?;
                         if i < args.len() - 1 {
                             buf.write_all(", ".as_bytes())?;
                         }
@@ -238,7 +281,7 @@ impl Interpreter for Referred {
                     if *asyncness {
                         buf.write_all(">".as_bytes())?;
                     }
-                    buf.write_all(";\n".as_bytes())?;
+                    buf.write_all(format!("{}\n", emitter.semi()).as_bytes())?;
                 } else {
                     return Err(E::Parsing(format!("Cannot find body of function {name}")));
                 }
@@ -267,22 +310,56 @@ impl Interpreter for Referred {
                     if field.is_field_ignored() {
                         continue;
                     }
-                    field.reference(natures, buf, offset.inc())?;
-                    buf.write_all(";\n".as_bytes())?;
+                    if !context.as_class() && field.is_method_static() {
+                        // Interfaces cannot declare static members
+                        continue;
+                    }
+                    if let Some((property, out)) = field.get_getter()? {
+                        buf.write_all(
+                            format!(
+                                "{}{}{property}: ",
+                                offset.inc(),
+                                if Nature::has_setter(fields, &property) {
+                                    ""
+                                } else {
+                                    "readonly "
+                                }
+                            )
+                            .as_bytes(),
+                        )?;
+                        out.reference(natures, buf, offset.inc())?;
+                    } else if let Some((property, arg)) = field.get_setter() {
+                        if Nature::has_getter(fields, &property)? {
+                            // Property is already declared by getter
+                            continue;
+                        }
+                        buf.write_all(format!("{}{property}: ", offset.inc()).as_bytes())?;
+                        arg.reference(natures, buf, offset.inc())?;
+                    } else {
+                        field.reference(natures, buf, offset.inc())?;
+                    }
+                    buf.write_all(format!("{}\n", emitter.semi()).as_bytes())?;
                 }
                 buf.write_all(format!("{offset}}}\n",).as_bytes())?;
             }
             Referred::TupleStruct(name, _context, field) => {
                 buf.write_all(format!("{offset}export type {name} = ").as_bytes())?;
                 if let Some(field) = field {
-                    field.reference(natures, buf, Offset::new())?;
+                    field.reference(natures, buf, emitter.offset())?;
                 } else {
                     buf.write_all("undefined".as_bytes())?;
                 }
-                buf.write_all(";\n".as_bytes())?;
+                buf.write_all(format!("{}\n", emitter.semi()).as_bytes())?;
             }
             Referred::Constant(name, _context, _ty, value) => {
-                buf.write_all(format!("{offset}export const {name} = {value};\n").as_bytes())?;
+                buf.write_all(
+                    format!(
+                        "{offset}export const {name} = {}{}\n",
+                        emitter.expression(value),
+                        emitter.semi()
+                    )
+                    .as_bytes(),
+                )?;
             }
             Referred::Ref(ref_name, ..) => {
                 return Err(E::Parsing(format!("Reference {ref_name} can be declared")));
@@ -296,30 +373,42 @@ impl Interpreter for Referred {
         Ok(())
     }
 
-    fn reference(
-        &self,
-        natures: &Natures,
-        buf: &mut BufWriter<File>,
-        offset: Offset,
-    ) -> Result<(), E> {
+    fn reference(&self, natures: &Natures, buf: &mut Vec<u8>, offset: Offset) -> Result<(), E> {
         match self {
             Referred::Enum(name, ..) => buf.write_all(name.as_bytes())?,
             Referred::EnumVariant(name, ..) => {
                 buf.write_all(format!("{offset}{name}: ").as_bytes())?
             }
             Referred::Field(name, context, nature, ..) => {
-                if let Nature::Composite(Composite::Func(_, _, _, _, constructor)) = nature.deref()
+                if let Nature::Composite(Composite::Func(_, _, _, _, constructor, static_method)) =
+                    nature.deref()
                 {
                     if *constructor {
                         buf.write_all(format!("{offset}constructor").as_bytes())?;
                     } else {
                         buf.write_all(
-                            format!("{offset}{}", context.rename_method(name)?).as_bytes(),
+                            format!(
+                                "{offset}{}{}",
+                                if *static_method { "static " } else { "" },
+                                context.rename_method(name)?
+                            )
+                            .as_bytes(),
                         )?;
                     }
                     nature.reference(natures, buf, offset)?;
                 } else {
-                    buf.write_all(format!("{offset}{}: ", context.rename_field(name)?).as_bytes())?;
+                    buf.write_all(
+                        format!(
+                            "{offset}{}{}: ",
+                            if context.as_readonly() {
+                                "readonly "
+                            } else {
+                                ""
+                            },
+                            context.rename_field(name)?
+                        )
+                        .as_bytes(),
+                    )?;
                     if let Nature::Referred(Referred::Ref(ref_name, _)) = nature.deref() {
                         if let Some(generic) = context.get_generic(ref_name) {
                             generic.reference(natures, buf, offset)?;
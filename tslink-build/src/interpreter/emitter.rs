@@ -0,0 +1,142 @@
+use crate::{
+    config::{
+        self,
+        cfg::{Indent, LineEndings, Style},
+    },
+    error::E,
+    interpreter::Offset,
+};
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use std::str::FromStr;
+use syn::Lit;
+
+/// Renders style-dependent tokens of generated `*.ts`, `*.d.ts` and `*.js` code: indentation,
+/// string literals, semicolons and line endings.
+///
+/// Interpreters render code only with tokens of the emitter (indentation is rendered by
+/// [`Offset`], which is created by the emitter), so generated code is written as it's rendered.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Emitter {
+    style: Style,
+}
+
+impl Emitter {
+    pub fn new(style: Style) -> Self {
+        Self { style }
+    }
+
+    /// Returns the emitter of the code style of the configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration cannot be accessed.
+    pub fn get() -> Result<Self, E> {
+        Ok(Self::new(config::get()?.style))
+    }
+
+    /// Returns the offset of top-level code.
+    pub fn offset(&self) -> Offset {
+        Offset::new(*self)
+    }
+
+    /// Returns the indentation of the given level.
+    pub fn indent(&self, level: usize) -> String {
+        match self.style.indent {
+            Indent::Spaces(width) => " ".repeat(level * width),
+            Indent::Tabs => "\t".repeat(level),
+        }
+    }
+
+    /// Returns the string literal of the value in configured quotes (`"value"`, `'value'`).
+    pub fn str(&self, value: &str) -> String {
+        let quote = self.style.quotes.as_char();
+        let mut literal = String::from(quote);
+        for c in value.chars() {
+            match c {
+                '\\' => literal.push_str("\\\\"),
+                '\n' => literal.push_str("\\n"),
+                '\r' => literal.push_str("\\r"),
+                '\t' => literal.push_str("\\t"),
+                c if c == quote => {
+                    literal.push('\\');
+                    literal.push(c);
+                }
+                c if c.is_control() => literal.push_str(&format!("\\u{{{:x}}}", c as u32)),
+                c => literal.push(c),
+            }
+        }
+        literal.push(quote);
+        literal
+    }
+
+    /// Returns the terminator of statements and members (`;` or nothing).
+    pub fn semi(&self) -> &'static str {
+        if self.style.semicolons {
+            ";"
+        } else {
+            ""
+        }
+    }
+
+    /// Renders the value of a constant (a Rust expression), where string and char literals
+    /// are rendered as string literals (see [`Emitter::str`]).
+    pub fn expression(&self, value: &str) -> String {
+        match TokenStream::from_str(value) {
+            Ok(tokens) => self.tokens(tokens),
+            Err(_) => value.to_owned(),
+        }
+    }
+
+    /// Renders tokens separated with spaces, except spaces before `,`, `;`, `.` and after `.`,
+    /// joint punctuation (`::`) and unary operators (`-1`).
+    fn tokens(&self, tokens: TokenStream) -> String {
+        let mut rendered = String::new();
+        // `true` if the next token is glued to the previous one
+        let mut glued = true;
+        // `true` if the previous token is an operator (or there is no previous token)
+        let mut operator = true;
+        for token in tokens.into_iter() {
+            let punct = match &token {
+                TokenTree::Punct(punct) => Some(punct.as_char()),
+                _ => None,
+            };
+            if !glued && !matches!(punct, Some(',' | ';' | '.')) {
+                rendered.push(' ');
+            }
+            glued = false;
+            match token {
+                TokenTree::Group(group) => {
+                    let inner = self.tokens(group.stream());
+                    rendered.push_str(&match group.delimiter() {
+                        Delimiter::Parenthesis => format!("({inner})"),
+                        Delimiter::Bracket => format!("[{inner}]"),
+                        Delimiter::Brace if inner.is_empty() => String::from("{}"),
+                        Delimiter::Brace => format!("{{ {inner} }}"),
+                        Delimiter::None => inner,
+                    });
+                }
+                TokenTree::Punct(punct) => {
+                    glued = punct.spacing() == Spacing::Joint
+                        || punct.as_char() == '.'
+                        || (operator && matches!(punct.as_char(), '-' | '!'));
+                    rendered.push(punct.as_char());
+                }
+                TokenTree::Ident(ident) => rendered.push_str(&ident.to_string()),
+                TokenTree::Literal(literal) => match syn::parse_str::<Lit>(&literal.to_string()) {
+                    Ok(Lit::Str(lit)) => rendered.push_str(&self.str(&lit.value())),
+                    Ok(Lit::Char(lit)) => rendered.push_str(&self.str(&lit.value().to_string())),
+                    _ => rendered.push_str(&literal.to_string()),
+                },
+            }
+            operator = punct.is_some();
+        }
+        rendered
+    }
+
+    /// Converts line endings of the rendered code (interpreters render lines with `\n`).
+    pub fn finish(&self, code: String) -> String {
+        match self.style.line_endings {
+            LineEndings::Lf => code,
+            LineEndings::Crlf => code.replace('\n', "\r\n"),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::{tuple, Interpreter};
+use crate::{
+    error::E,
+    interpreter::Emitter,
+    nature::{Composite, Natures},
+};
+
+/// Checks of composite types.
+///
+/// - `Array`, `Vec` → `Array.isArray(...)` and checks of all items
+/// - `HashMap` → an object (maps are passed as JSON objects) and checks of all values; in
+///   TypeScript maps are declared as `Map`, so instances of `Map` with valid keys and values
+///   are accepted as well
+/// - `Tuple` → `Array.isArray(...)`, the length and checks of items
+/// - `Option` → `null` or the check of the type
+/// - `Undefined` → `undefined`
+/// - `Func` → `typeof ... === "function"`
+/// - `Result` → the check of the result or the error (exceptions are suppressed)
+impl Interpreter for Composite {
+    fn guard(
+        &self,
+        natures: &Natures,
+        emitter: &Emitter,
+        value: &str,
+        typed: bool,
+    ) -> Result<String, E> {
+        Ok(match self {
+            Self::Array(ty) => format!(
+                "(Array.isArray({value}) && {value}.every((item) => {}))",
+                ty.guard(natures, emitter, "item", typed)?
+            ),
+            Self::Vec(_, ty) => {
+                if let Some(ty) = ty {
+                    format!(
+                        "(Array.isArray({value}) && {value}.every((item) => {}))",
+                        ty.guard(natures, emitter, "item", typed)?
+                    )
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type Vec doesn't include reference to type",
+                    )));
+                }
+            }
+            Self::HashMap(_, key, ty) => {
+                if let (Some(key), Some(ty)) = (key, ty) {
+                    // Keys of JSON objects are always strings
+                    let object = format!(
+                        "(typeof {value} === {} && {value} !== null && !Array.isArray({value}) && Object.values({value}).every((item) => {}))",
+                        emitter.str("object"),
+                        ty.guard(natures, emitter, "item", typed)?
+                    );
+                    if typed {
+                        format!(
+                            "({value} instanceof Map ? Array.from({value}).every(([key, item]) => {} && {}) : {object})",
+                            key.guard(natures, emitter, "key", typed)?,
+                            ty.guard(natures, emitter, "item", typed)?
+                        )
+                    } else {
+                        object
+                    }
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type HashMap doesn't include reference to type or key",
+                    )));
+                }
+            }
+            Self::Tuple(_, tys) => tuple(natures, emitter, tys, value, typed)?,
+            Self::Option(_, ty) => {
+                if let Some(ty) = ty {
+                    format!(
+                        "({value} === null || {})",
+                        ty.guard(natures, emitter, value, typed)?
+                    )
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type Option doesn't include reference to type",
+                    )));
+                }
+            }
+            Self::Undefined(..) => format!("{value} === undefined"),
+            Self::Func(..) => format!("typeof {value} === {}", emitter.str("function")),
+            Self::Result(_, res, err, ..) => {
+                let mut checks = Vec::new();
+                for ty in [res, err].into_iter().flatten() {
+                    checks.push(ty.guard(natures, emitter, value, typed)?);
+                }
+                if checks.is_empty() {
+                    format!("{value} === undefined")
+                } else {
+                    format!("({})", checks.join(" || "))
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,178 @@
+mod composite;
+mod primitive;
+mod refered;
+
+use crate::{
+    error::E,
+    interpreter::Emitter,
+    nature::{Composite, Nature, Natures, Referred},
+};
+use std::ops::Deref;
+
+/// Trait for rendering runtime checks of a given type (`Nature`), which are used by type guards
+/// (`isUser(value): value is User`) and assertions (`assertUser(value)`).
+///
+/// Checks are rendered from the same `Nature` tree as TypeScript declarations, so guards
+/// always match the declared types. Rendered expressions are valid TypeScript and JavaScript;
+/// non-trivial expressions are wrapped in parentheses, so they can be combined freely.
+pub trait Interpreter {
+    /// Returns the boolean expression, which checks the value of the given expression.
+    ///
+    /// # Parameters
+    /// - `natures`: The registry of all known types.
+    /// - `emitter`: Renders string literals.
+    /// - `value`: The checked expression (e.g., `value`, `value.field`, `item`).
+    /// - `typed`: `true` for `*.ts` targets, where flat enums are declared as TypeScript `enum`
+    ///   (`Kind.Circle`) and maps as `Map`; otherwise (`lib.js`) values of flat enums are names
+    ///   of variants (`"Circle"`) and maps are objects.
+    fn guard(
+        &self,
+        natures: &Natures,
+        emitter: &Emitter,
+        value: &str,
+        typed: bool,
+    ) -> Result<String, E>;
+}
+
+impl Interpreter for Nature {
+    /// Delegates rendering to the specific variant: `Primitive`, `Composite`, or `Referred`.
+    fn guard(
+        &self,
+        natures: &Natures,
+        emitter: &Emitter,
+        value: &str,
+        typed: bool,
+    ) -> Result<String, E> {
+        match self {
+            Self::Primitive(primitive) => primitive.guard(natures, emitter, value, typed),
+            Self::Composite(composite) => composite.guard(natures, emitter, value, typed),
+            Self::Referred(refered) => refered.guard(natures, emitter, value, typed),
+        }
+    }
+}
+
+/// Returns names of the type guard and the assertion of the given entity
+/// (`User` → `isUser`, `assertUser`).
+pub fn guard_names(name: &str) -> (String, String) {
+    (format!("is{name}"), format!("assert{name}"))
+}
+
+/// Returns `true` if guards are rendered for the entity: interfaces (structs, which aren't
+/// classes), type aliases (tuple structs) and enums. Instances of classes are checked with
+/// `instanceof`.
+pub fn has_guard(nature: &Nature) -> bool {
+    match nature {
+        Nature::Referred(Referred::Struct(_, context, _)) => !context.as_class(),
+        Nature::Referred(Referred::TupleStruct(..)) | Nature::Referred(Referred::Enum(..)) => true,
+        _ => false,
+    }
+}
+
+/// Renders the type guard and the assertion of the entity. TypeScript functions are exported
+/// and typed (`value is User`, `asserts value is User`); JavaScript functions are rendered
+/// without types and exports. Returns `None` if the entity doesn't get guards
+/// (see [`has_guard`]).
+///
+/// # Errors
+/// Returns an error if some type of the entity cannot be checked.
+pub fn declaration(
+    name: &str,
+    nature: &Nature,
+    natures: &Natures,
+    emitter: &Emitter,
+    typed: bool,
+) -> Result<Option<String>, E> {
+    if !has_guard(nature) {
+        return Ok(None);
+    }
+    let offset = emitter.offset();
+    let (is, assert) = guard_names(name);
+    let conditions = nature.guard(natures, emitter, "value", typed)?;
+    let (export, arg, is_out, assert_out) = if typed {
+        (
+            "export ",
+            "value: unknown",
+            format!(": value is {name}"),
+            format!(": asserts value is {name}"),
+        )
+    } else {
+        ("", "value", String::new(), String::new())
+    };
+    Ok(Some(format!(
+        "{export}function {is}({arg}){is_out} {{
+{}return {conditions}{semi}
+}}
+{export}function {assert}({arg}){assert_out} {{
+{}if (!{is}(value)) {{
+{}throw new TypeError({}){semi}
+{}}}
+}}
+",
+        offset.inc(),
+        offset.inc(),
+        offset.inc().inc(),
+        emitter.str(&format!("Value isn't {name}")),
+        offset.inc(),
+        semi = emitter.semi(),
+    )))
+}
+
+/// Returns declarations of the type guard and the assertion of the entity for `*.d.ts` files.
+pub fn dts_declaration(name: &str, emitter: &Emitter) -> String {
+    let (is, assert) = guard_names(name);
+    format!(
+        "export declare function {is}(value: unknown): value is {name}{semi}
+export declare function {assert}(value: unknown): asserts value is {name}{semi}
+",
+        semi = emitter.semi()
+    )
+}
+
+/// Returns the check of an object with the given fields (ignored fields, constructors and
+/// static methods are skipped; methods are checked as functions).
+fn object(
+    natures: &Natures,
+    emitter: &Emitter,
+    fields: &[Nature],
+    value: &str,
+    typed: bool,
+) -> Result<String, E> {
+    let mut conditions = vec![
+        format!("typeof {value} === {}", emitter.str("object")),
+        format!("{value} !== null"),
+    ];
+    for field in fields.iter() {
+        if let Nature::Referred(Referred::Field(name, context, nature, _)) = field {
+            if field.is_field_ignored() || field.is_method_constructor() || field.is_method_static()
+            {
+                continue;
+            }
+            let name = if matches!(nature.deref(), Nature::Composite(Composite::Func(..))) {
+                context.rename_method(name)?
+            } else {
+                context.rename_field(name)?
+            };
+            conditions.push(format!("{} in {value}", emitter.str(&name)));
+            conditions.push(field.guard(natures, emitter, &format!("{value}.{name}"), typed)?);
+        }
+    }
+    Ok(format!("({})", conditions.join(" && ")))
+}
+
+/// Returns the check of a tuple with the given items.
+fn tuple(
+    natures: &Natures,
+    emitter: &Emitter,
+    items: &[Nature],
+    value: &str,
+    typed: bool,
+) -> Result<String, E> {
+    let mut conditions = vec![
+        format!("Array.isArray({value})"),
+        format!("{value}.length === {}", items.len()),
+    ];
+    for (i, item) in items.iter().enumerate() {
+        conditions.push(item.guard(natures, emitter, &format!("{value}[{i}]"), typed)?);
+    }
+    Ok(format!("({})", conditions.join(" && ")))
+}
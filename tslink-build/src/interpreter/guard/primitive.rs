@@ -0,0 +1,27 @@
+use super::Interpreter;
+use crate::{
+    error::E,
+    interpreter::Emitter,
+    nature::{Natures, Primitive},
+};
+
+/// Checks of primitive types with `typeof`.
+impl Interpreter for Primitive {
+    fn guard(
+        &self,
+        _natures: &Natures,
+        emitter: &Emitter,
+        value: &str,
+        _typed: bool,
+    ) -> Result<String, E> {
+        Ok(format!(
+            "typeof {value} === {}",
+            emitter.str(match self {
+                Self::Number(..) => "number",
+                Self::BigInt(..) => "bigint",
+                Self::String(..) => "string",
+                Self::Boolean(..) => "boolean",
+            })
+        ))
+    }
+}
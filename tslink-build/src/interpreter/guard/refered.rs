@@ -0,0 +1,134 @@
+use super::{guard_names, has_guard, object, tuple, Interpreter};
+use crate::{
+    config::cfg::EnumRepresentation,
+    error::E,
+    interpreter::Emitter,
+    nature::{Nature, Natures, Referred},
+};
+use std::ops::Deref;
+
+/// Checks of named entities.
+///
+/// - `Struct` → `typeof ... === "object"`, presence and checks of all fields
+/// - `TupleStruct` → the check of the inner type (`undefined` for unit structs)
+/// - `Enum` → depends on variants and `EnumRepresentation` (the same as TypeScript declaration):
+///   - flat (no data in variants) → comparison with each variant
+///   - `Flat` → an object, each property of which is `undefined` or data of the variant
+///   - `Union` → an object with a property named after one of variants
+///   - `DiscriminatedUnion` → as `Union`, but variants without data are strings
+/// - `EnumVariant` → data of the variant: `null`, the single type, a tuple or an object
+/// - `Field` → the check of its type (generic parameters are resolved)
+/// - `Ref` → the guard of the entity (`isUser(...)`); for classes and unknown entities only
+///   the presence of the value is checked
+/// - `Func`, `FuncArg`, `Constant`, `Generic` → not supported
+impl Interpreter for Referred {
+    fn guard(
+        &self,
+        natures: &Natures,
+        emitter: &Emitter,
+        value: &str,
+        typed: bool,
+    ) -> Result<String, E> {
+        Ok(match self {
+            Referred::Struct(_, _, fields) => object(natures, emitter, fields, value, typed)?,
+            Referred::TupleStruct(_, _, field) => {
+                if let Some(field) = field {
+                    field.guard(natures, emitter, value, typed)?
+                } else {
+                    format!("{value} === undefined")
+                }
+            }
+            Referred::Enum(name, _, variants, repres, _) => {
+                let flat = Referred::is_flat_varians(variants)?;
+                let mut checks = Vec::new();
+                for variant in variants.iter() {
+                    if let Nature::Referred(Referred::EnumVariant(variant_name, _, fields, ..)) =
+                        variant
+                    {
+                        let access = format!("{value}.{variant_name}");
+                        checks.push(if flat && typed {
+                            format!("{value} === {name}.{variant_name}")
+                        } else if flat {
+                            format!("{value} === {}", emitter.str(variant_name))
+                        } else {
+                            match repres {
+                                EnumRepresentation::Flat => format!(
+                                    "(!({} in {value}) || {access} === undefined || {})",
+                                    emitter.str(variant_name),
+                                    variant.guard(natures, emitter, &access, typed)?
+                                ),
+                                EnumRepresentation::DiscriminatedUnion if fields.is_empty() => {
+                                    format!("{value} === {}", emitter.str(variant_name))
+                                }
+                                EnumRepresentation::Union
+                                | EnumRepresentation::DiscriminatedUnion => format!(
+                                    "(typeof {value} === {} && {value} !== null && {} in {value} && {})",
+                                    emitter.str("object"),
+                                    emitter.str(variant_name),
+                                    variant.guard(natures, emitter, &access, typed)?
+                                ),
+                            }
+                        });
+                    }
+                }
+                if checks.is_empty() {
+                    String::from("false")
+                } else if !flat && matches!(repres, EnumRepresentation::Flat) {
+                    format!(
+                        "(typeof {value} === {} && {value} !== null && {})",
+                        emitter.str("object"),
+                        checks.join(" && ")
+                    )
+                } else {
+                    format!("({})", checks.join(" || "))
+                }
+            }
+            Referred::EnumVariant(_, _, fields, ..) => {
+                if fields.is_empty() {
+                    format!("{value} === null")
+                } else if fields
+                    .iter()
+                    .any(|f| matches!(f, Nature::Referred(Referred::Field(..))))
+                {
+                    object(natures, emitter, fields, value, typed)?
+                } else if fields.len() == 1 {
+                    fields
+                        .first()
+                        .ok_or(E::Parsing(String::from(
+                            "Expecting single field for Variant",
+                        )))?
+                        .guard(natures, emitter, value, typed)?
+                } else {
+                    tuple(natures, emitter, fields, value, typed)?
+                }
+            }
+            Referred::Field(_, context, nature, _) => {
+                if let Nature::Referred(Referred::Ref(ref_name, _)) = nature.deref() {
+                    if let Some(generic) = context.get_generic(ref_name) {
+                        return generic.guard(natures, emitter, value, typed);
+                    }
+                }
+                nature.guard(natures, emitter, value, typed)?
+            }
+            Referred::Ref(ref_name, ..) => {
+                if natures.get_entity(ref_name).is_some_and(has_guard) {
+                    format!("{}({value})", guard_names(ref_name).0)
+                } else {
+                    format!("{value} !== undefined")
+                }
+            }
+            Referred::Func(name, ..)
+            | Referred::FuncArg(name, ..)
+            | Referred::Constant(name, ..) => {
+                return Err(E::NotSupported(format!(
+                    "{name} cannot be checked with type guard"
+                )));
+            }
+            Referred::Generic(alias, ..) => {
+                return Err(E::Parsing(format!(
+                    "Generic type cannot be rendered out of context; type alias = {alias}"
+                )))
+            }
+        })
+    }
+}
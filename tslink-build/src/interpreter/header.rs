@@ -0,0 +1,60 @@
+use crate::{config::Config, error::E, package::value};
+use std::{fs, path::Path};
+
+/// Prefix of the header line with the hash of the content of a generated file.
+const HASH_PREFIX: &str = "// tslink-hash: ";
+
+/// Returns the FNV-1a hash of the content as hex string.
+///
+/// The hash is stable between builds and versions of Rust, so it can be stored in files.
+pub fn hash(content: &str) -> String {
+    let hash = content.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Renders the header of a generated file with the given content: the crate, its version,
+/// the version of tslink, "do not edit" notice and the hash of the content.
+///
+/// # Errors
+/// Returns an error if the name of the crate cannot be read from `Cargo.toml`.
+pub fn render(config: &Config, content: &str) -> Result<String, E> {
+    let version = config
+        .get_cargo()
+        .get("package")
+        .and_then(|package| value(package, "version").ok())
+        .map(|version| format!(" {version}"))
+        .unwrap_or_default();
+    Ok(format!(
+        "// Generated by tslink {} from crate {}{version}.
+// DO NOT EDIT: manual changes will be lost on the next build.
+{HASH_PREFIX}{}
+",
+        env!("CARGO_PKG_VERSION"),
+        config.package_name()?,
+        hash(content)
+    ))
+}
+
+/// Returns `true` if the existing file has the header of tslink, but its content doesn't match
+/// the hash of the header, i.e. the file has been modified manually after generation.
+///
+/// Missing files and files without header aren't considered modified.
+pub fn is_modified(path: &Path) -> bool {
+    let current = match fs::read_to_string(path) {
+        Ok(current) => current,
+        Err(_) => return false,
+    };
+    let mut offset = 0;
+    for line in current.split_inclusive('\n') {
+        if !line.starts_with("//") {
+            return false;
+        }
+        offset += line.len();
+        if let Some(hash_value) = line.strip_prefix(HASH_PREFIX) {
+            return hash_value.trim_end() != hash(&current[offset..]);
+        }
+    }
+    false
+}
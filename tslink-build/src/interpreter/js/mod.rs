@@ -0,0 +1,309 @@
+mod refered;
+mod validate;
+
+use crate::{
+    config::{
+        self,
+        cfg::{Backend, ModuleFormat},
+    },
+    error::E,
+    interpreter::{emit_code, guard, specifier, zod, Emitter, Offset, Output},
+    nature::{Nature, Natures, Referred, TypeAsString},
+};
+use std::{io::Write, path::Path};
+
+pub trait Interpreter {
+    fn declaration(
+        &self,
+        _natures: &Natures,
+        _buf: &mut Vec<u8>,
+        _offset: Offset,
+        _format: &ModuleFormat,
+    ) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+/// Returns the beginning of the export of a value with the given name
+/// (`exports.{name} = ` or `export const {name} = `).
+pub fn export_assign(format: &ModuleFormat, name: &str) -> String {
+    if format == &ModuleFormat::Esm {
+        format!("export const {name} = ")
+    } else {
+        format!("exports.{name} = ")
+    }
+}
+
+/// Returns the export of the declared binding `alias` under the given name.
+pub fn export_binding(format: &ModuleFormat, name: &str, alias: &str, emitter: &Emitter) -> String {
+    let semi = emitter.semi();
+    if format != &ModuleFormat::Esm {
+        format!("exports.{name} = {alias}{semi}")
+    } else if name == alias {
+        format!("export {{ {name} }}{semi}")
+    } else {
+        format!("export {{ {alias} as {name} }}{semi}")
+    }
+}
+
+/// Returns the beginning of the module, which loads the native module into `nativeModuleRef`.
+/// With `wasm-bindgen` the module generated by wasm-bindgen is imported as is, so bundlers
+/// (or the nodejs target of wasm-bindgen) take care of loading of WebAssembly.
+fn prelude(
+    format: &ModuleFormat,
+    backend: &Backend,
+    node_module: &str,
+    emitter: &Emitter,
+) -> String {
+    let offset = emitter.offset();
+    let semi = emitter.semi();
+    let node_module = emitter.str(node_module);
+    let strict = format!(
+        "{}{semi}
+Object.defineProperty(exports, {}, {{ value: true }}){semi}
+",
+        emitter.str("use strict"),
+        emitter.str("__esModule")
+    );
+    if backend == &Backend::WasmBindgen {
+        return if format == &ModuleFormat::Esm {
+            format!("import * as nativeModuleRef from {node_module}{semi}\n")
+        } else {
+            format!("{strict}\nconst nativeModuleRef = require({node_module}){semi}\n")
+        };
+    }
+    let (imports, location) = if format == &ModuleFormat::Esm {
+        (
+            format!(
+                "import {{ createRequire }} from {}{semi}
+import {{ fileURLToPath }} from {}{semi}
+import path from {}{semi}
+import fs from {}{semi}
+
+const require = createRequire(import.meta.url){semi}
+",
+                emitter.str("module"),
+                emitter.str("url"),
+                emitter.str("path"),
+                emitter.str("fs")
+            ),
+            "path.dirname(fileURLToPath(import.meta.url))",
+        )
+    } else {
+        (
+            format!(
+                "{strict}
+const path = require({}){semi}
+const fs = require({}){semi}
+",
+                emitter.str("path"),
+                emitter.str("fs")
+            ),
+            "module.path",
+        )
+    };
+    format!(
+        "{imports}
+function native() {{
+{i1}const modulePath = path.resolve({location}, {node_module}){semi}
+{i1}if (!fs.existsSync(modulePath)) {{
+{i2}throw new Error(`Fail to find native module in: ${{modulePath}}`){semi}
+{i1}}}
+{i1}return require(modulePath){semi}
+}}
+const nativeModuleRef = native(){semi}
+",
+        i1 = offset.inc(),
+        i2 = offset.inc().inc(),
+    )
+}
+
+/// Returns zod schemas of entities and the helper `$$validate`, which checks arguments bound
+/// to JSON (`zod = true`); otherwise an empty string.
+fn schemas(natures: &Natures, format: &ModuleFormat, emitter: &Emitter) -> Result<String, E> {
+    if !config::get()?.zod {
+        return Ok(String::new());
+    }
+    let semi = emitter.semi();
+    let mut buf = if format == &ModuleFormat::Esm {
+        format!("import {{ z }} from {}{semi}\n", emitter.str("zod"))
+    } else {
+        format!("const {{ z }} = require({}){semi}\n", emitter.str("zod"))
+    };
+    buf.push_str(&format!(
+        "
+function $$validate(schema, value) {{
+{i1}schema.parse(value){semi}
+{i1}return value{semi}
+}}
+",
+        i1 = emitter.offset().inc()
+    ));
+    for (name, nature) in natures.iter() {
+        if let Some(schema) = zod::declaration(name, nature, natures, emitter, false)? {
+            buf.push_str(&schema);
+        }
+    }
+    Ok(buf)
+}
+
+/// Returns type guards and assertions of entities (`type_guards = true`, or `validate_args = true`
+/// to check arguments bound to JSON); otherwise an empty string. Functions aren't exported, see
+/// [`guard_exports`].
+fn guards(natures: &Natures, emitter: &Emitter) -> Result<String, E> {
+    let config = config::get()?;
+    if !config.type_guards && !config.validate_args {
+        return Ok(String::new());
+    }
+    let mut buf = String::new();
+    for (name, nature) in natures.iter() {
+        if let Some(guards) = guard::declaration(name, nature, natures, emitter, false)? {
+            buf.push_str(&guards);
+        }
+    }
+    Ok(buf)
+}
+
+/// Returns exports of the type guard and the assertion of the entity, if the entity has them
+/// and `type_guards = true`.
+fn guard_exports(nature: &Nature, format: &ModuleFormat, emitter: &Emitter) -> Result<String, E> {
+    if !config::get()?.type_guards || !guard::has_guard(nature) {
+        return Ok(String::new());
+    }
+    let (is, assert) = guard::guard_names(&nature.type_as_string()?);
+    Ok(format!(
+        "{}\n{}\n",
+        export_binding(format, &is, &is, emitter),
+        export_binding(format, &assert, &assert, emitter)
+    ))
+}
+
+/// Returns `true` if the entity exists at runtime (classes, functions, constants, flat enums
+/// and enums with methods); other entities exist only as types.
+fn has_runtime(nature: &Nature) -> Result<bool, E> {
+    Ok(match nature {
+        Nature::Referred(en_nature @ Referred::Enum(_, _, _, _, methods)) => {
+            en_nature.is_enum_flat()? || !methods.is_empty()
+        }
+        Nature::Referred(Referred::Struct(_, context, _)) => context.as_class(),
+        Nature::Referred(Referred::Func(..)) | Nature::Referred(Referred::Constant(..)) => true,
+        _ => false,
+    })
+}
+
+/// Renders the JavaScript module of the given format.
+fn render(natures: &Natures, node_module: &str, format: &ModuleFormat) -> Result<String, E> {
+    let config = config::get()?;
+    let emitter = Emitter::new(config.style);
+    let mut buf_writer = Vec::new();
+    buf_writer.write_all(prelude(format, &config.backend, node_module, &emitter).as_bytes())?;
+    buf_writer.write_all(schemas(natures, format, &emitter)?.as_bytes())?;
+    buf_writer.write_all(guards(natures, &emitter)?.as_bytes())?;
+    for (_, nature) in natures.iter() {
+        buf_writer.write_all(guard_exports(nature, format, &emitter)?.as_bytes())?;
+    }
+    for en_nature in natures.filter(|n| matches!(n, Nature::Referred(Referred::Enum(..)))) {
+        if has_runtime(&en_nature)? {
+            if let Nature::Referred(en_nature) = en_nature {
+                en_nature.declaration(natures, &mut buf_writer, emitter.offset(), format)?;
+            }
+        }
+    }
+    for (_, nature) in natures.iter() {
+        if matches!(nature, Nature::Referred(Referred::Enum(..))) || !has_runtime(nature)? {
+            continue;
+        }
+        if let Nature::Referred(nature) = nature {
+            nature.declaration(natures, &mut buf_writer, emitter.offset(), format)?;
+        }
+    }
+    Ok(String::from_utf8_lossy(&buf_writer).to_string())
+}
+
+/// Returns the specifier of the native module for a JavaScript module in the given folder
+/// (the native module is loaded relative to the folder of the JavaScript module).
+fn native_module(location: &Path) -> Result<String, E> {
+    let config = config::get()?;
+    let dist = config
+        .node_mod_dist
+        .clone()
+        .ok_or(E::InvalidConfiguration(String::from(
+            "No path to folder with node module. Set correct path in [tslink] of Cargo.toml; field \"node\"",
+        )))?;
+    let node_module = config
+        .node_mod_filename
+        .clone()
+        .ok_or(E::InvalidConfiguration(String::from(
+            "No node module file name. Set correct path in [tslink] of Cargo.toml; field \"node\"",
+        )))?;
+    specifier(location, &dist.join(node_module))
+}
+
+pub fn write(natures: &Natures) -> Result<(), E> {
+    let config = config::get()?;
+    let js_file = config.js_file.clone();
+    let node_module = native_module(js_file.parent().unwrap_or(&js_file))?;
+    let module_format = config.module_format.clone();
+    drop(config);
+    match module_format {
+        ModuleFormat::Cjs | ModuleFormat::Esm => {
+            emit_code(&js_file, &render(natures, &node_module, &module_format)?)?;
+        }
+        ModuleFormat::Dual => {
+            let cjs = render(natures, &node_module, &ModuleFormat::Cjs)?;
+            emit_code(&js_file, &cjs)?;
+            emit_code(&js_file.with_extension("cjs"), &cjs)?;
+            emit_code(
+                &js_file.with_extension("mjs"),
+                &render(natures, &node_module, &ModuleFormat::Esm)?,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Renders the runtime part of the entity into its own JavaScript module
+/// (`#[tslink(target = "./file.js")]`). The module loads the native module by itself, so
+/// it doesn't depend on `lib.js`. The module is ESM if `format = "esm"` and CommonJS otherwise.
+/// Entities, which exist only as types, don't add anything to the module, except exports
+/// of their type guards (`type_guards = true`).
+///
+/// # Errors
+/// Returns an error if the node module isn't configured or rendering fails.
+pub fn write_item(
+    entity: &Nature,
+    natures: &Natures,
+    path: &Path,
+    output: &mut Output,
+) -> Result<(), E> {
+    let config = config::get()?;
+    let emitter = Emitter::new(config.style);
+    let format = if config.module_format == ModuleFormat::Esm {
+        ModuleFormat::Esm
+    } else {
+        ModuleFormat::Cjs
+    };
+    if output.get(path).is_none() {
+        output.append(
+            path,
+            prelude(
+                &format,
+                &config.backend,
+                &native_module(path.parent().unwrap_or(path))?,
+                &emitter,
+            ),
+        );
+        output.append(path, schemas(natures, &format, &emitter)?);
+        output.append(path, guards(natures, &emitter)?);
+    }
+    output.append(path, guard_exports(entity, &format, &emitter)?);
+    if !has_runtime(entity)? {
+        return Ok(());
+    }
+    if let Nature::Referred(entity) = entity {
+        let mut buf_writer = Vec::new();
+        entity.declaration(natures, &mut buf_writer, emitter.offset(), &format)?;
+        output.append(path, String::from_utf8_lossy(&buf_writer));
+    }
+    Ok(())
+}
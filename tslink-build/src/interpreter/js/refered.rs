@@ -0,0 +1,588 @@
+use super::{export_assign, export_binding, validate, Interpreter};
+use crate::{
+    config::{
+        self,
+        cfg::{Backend, ModuleFormat},
+    },
+    error::E,
+    interpreter::{
+        enum_method_name,
+        zod::{has_schema, schema_name},
+        Offset,
+    },
+    nature::{Composite, Nature, Natures, Referred},
+};
+use std::{collections::BTreeSet, io::Write, ops::Deref};
+
+/// Returns arguments of a call, where arguments bound to JSON (`data = "User"`) are passed
+/// as JSON strings. With `zod = true` such arguments are validated with the zod schema of
+/// the bound entity before `JSON.stringify`.
+fn bind_args(args: &[String], bound: &[(String, String)], natures: &Natures) -> Result<String, E> {
+    let zod = config::get()?.zod;
+    Ok(args
+        .iter()
+        .map(|a| match bound.iter().find(|(name, _)| name == a) {
+            Some((_, entity)) if zod && natures.get_entity(entity).is_some_and(has_schema) => {
+                format!("JSON.stringify($$validate({}, {a}))", schema_name(entity))
+            }
+            Some(_) => format!("JSON.stringify({a})"),
+            None => a.to_owned(),
+        })
+        .collect::<Vec<String>>()
+        .join(", "))
+}
+
+/// Wraps the result of the native module: JSON is parsed; an instance of the class is wrapped
+/// with the class wrapper (`$$User.#wrap(result)`), if `instance` is given.
+fn wrap_output(call_exp: String, result_as_json: bool, instance: Option<&str>) -> String {
+    if let Some(alias) = instance {
+        return format!("{alias}.#wrap({call_exp})");
+    }
+    format!(
+        "{}{call_exp}{}",
+        if result_as_json { "JSON.parse(" } else { "" },
+        if result_as_json { ")" } else { "" }
+    )
+}
+
+/// Returns `true` if the method returns an instance of the struct (`-> Self`), which should be
+/// wrapped with the class wrapper; results passed as JSON aren't wrapped.
+fn returns_instance(nature: &Nature, struct_name: &str, result_as_json: bool) -> bool {
+    if result_as_json {
+        return false;
+    }
+    if let Nature::Composite(Composite::Func(_, _, Some(out), _, false, _)) = nature {
+        if let Nature::Composite(Composite::Result(_, Some(res), ..)) = out.deref() {
+            return matches!(res.deref(), Nature::Referred(Referred::Ref(name, _)) if name == struct_name);
+        }
+    }
+    false
+}
+
+/// Returns statements, which handle the error `e` of the native module. Statements are
+/// rendered with the given offset.
+fn wrap_err(
+    error_as_json: bool,
+    asyncness: bool,
+    exception_suppression: bool,
+    offset: &Offset,
+) -> Result<String, E> {
+    let emitter = offset.emitter();
+    let semi = emitter.semi();
+    let i1 = offset.inc();
+    let open = if asyncness { "Promise.reject(" } else { "" };
+    let close = if asyncness { ")" } else { "" };
+    let returning = if exception_suppression || asyncness {
+        "return"
+    } else {
+        "throw"
+    };
+    let parsing_err_block = if exception_suppression || asyncness {
+        format!(
+            "{i1}try {{
+{i2}const err = new Error(`Function/method returns error;`){semi}
+{i2}err.err = JSON.parse(e){semi}
+{i2}return {open}err{close}{semi}
+{i1}}} catch(err_parsing) {{
+{i2}return {open}new Error(`Function/method returns error; fail to parse error; origin error: ${{e}}; error: ${{err_parsing}}`){close}{semi}
+{i1}}}
+",
+            i2 = i1.inc()
+        )
+    } else {
+        format!(
+            "{i1}const err = new Error(`Function/method returns error;`){semi}
+{i1}try {{
+{i2}err.err = JSON.parse(e){semi}
+{i1}}} catch(err_parsing) {{
+{i2}throw new Error(`Function/method returns error; fail to parse error; origin error: ${{e}}; error: ${{err_parsing}}`){semi}
+{i1}}}
+{i1}throw err{semi}
+",
+            i2 = i1.inc()
+        )
+    };
+    // napi-rs throws errors as Error objects with JSON string in the message
+    let unwrapping_block = if error_as_json && config::get()?.backend == Backend::NapiRs {
+        format!(
+            "{offset}if (e instanceof Error && e.code === {}) {{
+{i1}e = e.message{semi}
+{offset}}}
+",
+            emitter.str("GenericFailure")
+        )
+    } else {
+        String::new()
+    };
+    let string_block = if error_as_json {
+        parsing_err_block
+    } else {
+        format!("{i1}{returning} {open}new Error(e){close}{semi}\n")
+    };
+    Ok(format!(
+        "{unwrapping_block}{offset}if (e instanceof Error) {{
+{i1}{returning} {open}e{close}{semi}
+{offset}}}
+{offset}if (typeof e === {}) {{
+{string_block}{offset}}} else {{
+{i1}const err = new Error(`Function/method returns error; property [err] = ${{typeof e === {} && e !== null ? JSON.stringify(e) : e}}`){semi}
+{i1}err.err = e{semi}
+{i1}{returning} {open}err{close}{semi}
+{offset}}}
+",
+        emitter.str("string"),
+        emitter.str("object")
+    ))
+}
+
+/// Returns statements of the body of a function, which calls the native module. Statements
+/// are rendered with the given offset.
+fn fn_body(
+    call_exp: String,
+    exception_suppression: bool,
+    result_as_json: bool,
+    instance: Option<&str>,
+    error_as_json: bool,
+    asyncness: bool,
+    offset: &Offset,
+) -> Result<String, E> {
+    let semi = offset.emitter().semi();
+    let i1 = offset.inc();
+    Ok(if asyncness {
+        format!(
+            "{offset}return {call_exp}.then((result) => {{
+{i1}try {{
+{i2}return Promise.resolve({}){semi}
+{i1}}} catch (e) {{
+{i2}return Promise.reject(e){semi}
+{i1}}}
+{offset}}}).catch((e) => {{
+{}{offset}}}){semi}
+",
+            wrap_output("result".to_string(), result_as_json, instance),
+            wrap_err(error_as_json, asyncness, exception_suppression, &i1)?,
+            i2 = i1.inc()
+        )
+    } else if exception_suppression || error_as_json {
+        format!(
+            "{offset}try {{
+{i1}return {}{semi}
+{offset}}} catch(e) {{
+{}{offset}}}
+",
+            wrap_output(call_exp, result_as_json, instance),
+            wrap_err(error_as_json, asyncness, exception_suppression, &i1)?,
+        )
+    } else {
+        format!(
+            "{offset}return {}{semi}\n",
+            wrap_output(call_exp, result_as_json, instance)
+        )
+    })
+}
+
+impl Interpreter for Referred {
+    fn declaration(
+        &self,
+        natures: &Natures,
+        buf: &mut Vec<u8>,
+        offset: Offset,
+        format: &ModuleFormat,
+    ) -> Result<(), E> {
+        let emitter = offset.emitter();
+        let semi = emitter.semi();
+        match self {
+            Referred::Struct(struct_name, _, fields) => {
+                buf.write_all(
+                    format!("\n{offset}const {{ {struct_name} }} = nativeModuleRef{semi}")
+                        .as_bytes(),
+                )?;
+                if Natures::is_any_bound(fields) {
+                    buf.write_all(
+                        format!(
+                            "\n{offset}{}",
+                            export_binding(format, struct_name, struct_name, &emitter)
+                        )
+                        .as_bytes(),
+                    )?;
+                } else {
+                    // Members of the class and statements of their bodies
+                    let member = offset.inc();
+                    let body = member.inc();
+                    let alias = format!("$${struct_name}");
+                    buf.write_all(
+                        format!("\n{offset}class {alias} {{\n{member}#_origin{semi}").as_bytes(),
+                    )?;
+                    // Instances returned by methods (`-> Self`) are wrapped without calling of
+                    // the native constructor: the constructor takes the prepared instance
+                    let mut wrapping = false;
+                    for field in fields.iter() {
+                        if let Nature::Referred(Referred::Field(_, context, nature, _)) = field {
+                            wrapping = wrapping
+                                || returns_instance(nature, struct_name, context.result_as_json()?);
+                        }
+                    }
+                    let adopt = if wrapping {
+                        buf.write_all(
+                            format!(
+                                "
+{member}static #wrapped{semi}
+{member}static #wrap(origin) {{
+{body}{alias}.#wrapped = origin{semi}
+{body}return new {alias}(){semi}
+{member}}}"
+                            )
+                            .as_bytes(),
+                        )?;
+                        format!(
+                            "{body}if ({alias}.#wrapped !== undefined) {{
+{i1}this.#_origin = {alias}.#wrapped{semi}
+{i1}{alias}.#wrapped = undefined{semi}
+{i1}return{semi}
+{body}}}
+",
+                            i1 = body.inc()
+                        )
+                    } else {
+                        String::new()
+                    };
+                    // Render fields
+                    for field in fields.iter() {
+                        if let Nature::Referred(Referred::Field(name, _, nature, _)) = field {
+                            if matches!(nature.deref(), Nature::Composite(Composite::Func(..))) {
+                                continue;
+                            }
+                            buf.write_all(
+                                format!(
+                                    "
+{member}get {name}() {{
+{body}return this.#_origin.{name}{semi}
+{member}}}"
+                                )
+                                .as_bytes(),
+                            )?;
+                            if !field.is_field_readonly() {
+                                buf.write_all(
+                                    format!(
+                                        "
+{member}set {name}(v) {{
+{body}this.#_origin.{name} = v{semi}
+{member}}}"
+                                    )
+                                    .as_bytes(),
+                                )?;
+                            }
+                        }
+                    }
+                    // Render constructor
+                    let mut constuctor_rendered = false;
+                    for field in fields.iter() {
+                        if let Nature::Referred(Referred::Field(_, context, nature, _)) = field {
+                            if let Nature::Composite(Composite::Func(_, args, _, _, true, _)) =
+                                &**nature
+                            {
+                                let bound = context.get_bound_args();
+                                let checks = validate::checks(
+                                    struct_name,
+                                    args,
+                                    &bound,
+                                    natures,
+                                    false,
+                                    false,
+                                    &body,
+                                )?;
+                                if bound.is_empty() {
+                                    let args = Natures::get_fn_args_names(args).join(", ");
+                                    buf.write_all(
+                                        format!(
+                                            "
+{member}constructor({args}) {{
+{adopt}{checks}{body}this.#_origin = new {struct_name}({args}){semi}
+{member}}}"
+                                        )
+                                        .as_bytes(),
+                                    )?;
+                                } else {
+                                    let names = Natures::get_fn_args_names(args);
+                                    buf.write_all(
+                                        format!(
+                                            "\n
+{member}constructor({}) {{
+{adopt}{checks}{body}this.#_origin = new {struct_name}({}){semi}
+{member}}}",
+                                            names.join(", "),
+                                            bind_args(&names, &bound, natures)?
+                                        )
+                                        .as_bytes(),
+                                    )?;
+                                }
+                                constuctor_rendered = true;
+                            }
+                        }
+                    }
+                    if !constuctor_rendered {
+                        buf.write_all(
+                            format!(
+                                "\n
+{member}constructor() {{
+{adopt}{body}this.#_origin = new {struct_name}(){semi}
+{member}}}"
+                            )
+                            .as_bytes(),
+                        )?;
+                    }
+                    // Render methods
+                    for field in fields.iter() {
+                        if let Nature::Referred(Referred::Field(name, context, nature, _)) = field {
+                            if let Nature::Composite(Composite::Func(
+                                _,
+                                args,
+                                _,
+                                asyncness,
+                                constructor,
+                                static_method,
+                            )) = nature.deref()
+                            {
+                                if *constructor {
+                                    continue;
+                                }
+                                let name = context.rename_field(name)?;
+                                let bound = context.get_bound_args();
+                                let checks = validate::checks(
+                                    &format!("{struct_name}.{name}"),
+                                    args,
+                                    &bound,
+                                    natures,
+                                    context.exception_suppression()?,
+                                    *asyncness,
+                                    &body,
+                                )?;
+                                let args = Natures::get_fn_args_names(args);
+                                // Static methods are forwarded to native constructor object;
+                                // getters and setters are exposed as properties
+                                let (modifier, caller, member_name) = if *static_method {
+                                    ("static ", struct_name.to_owned(), name.clone())
+                                } else if let Some((property, _)) = field.get_getter()? {
+                                    ("get ", String::from("this.#_origin"), property)
+                                } else if let Some((property, _)) = field.get_setter() {
+                                    ("set ", String::from("this.#_origin"), property)
+                                } else {
+                                    ("", String::from("this.#_origin"), name.clone())
+                                };
+                                let call_exp = if bound.is_empty() {
+                                    format!("{caller}.{name}({})", args.join(", "))
+                                } else {
+                                    format!(
+                                        "{caller}.{name}({})",
+                                        bind_args(&args, &bound, natures)?
+                                    )
+                                };
+                                buf.write_all(
+                                    format!(
+                                        "
+{member}{modifier}{member_name}({}) {{
+{checks}{}{member}}}",
+                                        args.join(", "),
+                                        fn_body(
+                                            call_exp,
+                                            context.exception_suppression()?,
+                                            context.result_as_json()?,
+                                            returns_instance(
+                                                nature,
+                                                struct_name,
+                                                context.result_as_json()?,
+                                            )
+                                            .then_some(alias.as_str()),
+                                            context.error_as_json()?,
+                                            *asyncness,
+                                            &body,
+                                        )?
+                                    )
+                                    .as_bytes(),
+                                )?;
+                            }
+                        }
+                    }
+                    buf.write_all(format!("\n{offset}}}").as_bytes())?;
+                    buf.write_all(
+                        format!(
+                            "\n{offset}{}\n",
+                            export_binding(format, struct_name, &alias, &emitter)
+                        )
+                        .as_bytes(),
+                    )?;
+                }
+            }
+            Referred::Enum(name, _context, variants, _, methods) => {
+                buf.write_all(
+                    format!("{offset}{}Object.freeze({{\n", export_assign(format, name)).as_bytes(),
+                )?;
+                let flat = Referred::is_flat_varians(variants)?;
+                for (i, variant) in variants.iter().enumerate().filter(|_| flat) {
+                    if let Nature::Referred(Referred::EnumVariant(name, ..)) = variant {
+                        let value = emitter.str(name);
+                        buf.write_all(
+                            format!(
+                                "{}{name}: {value}, {i}: {value}, {}: {value},\n",
+                                offset.inc(),
+                                emitter.str(&i.to_string())
+                            )
+                            .as_bytes(),
+                        )?;
+                    } else {
+                        return Err(E::Parsing(String::from(
+                            "Given nature isn't Enum's variant",
+                        )));
+                    }
+                }
+                // Methods are forwarded to native functions named as "{enum}_{method}", which
+                // get values of the enum as JSON strings (see `modificator::enum_method`)
+                for method in methods.iter() {
+                    if let Nature::Referred(Referred::Field(method_name, context, func, _)) = method
+                    {
+                        if let Nature::Composite(Composite::Func(_, args, out, asyncness, ..)) =
+                            func.deref()
+                        {
+                            let native_name =
+                                context.rename_method(&enum_method_name(name, method_name))?;
+                            let mut bound = context.get_bound_args();
+                            for arg in args.iter() {
+                                if let Nature::Referred(Referred::FuncArg(arg, _, nature, _)) = arg
+                                {
+                                    if matches!(nature.deref(), Nature::Referred(Referred::Ref(ty, _)) if ty == name)
+                                    {
+                                        bound.push((arg.to_owned(), name.to_owned()));
+                                    }
+                                }
+                            }
+                            let result_as_json = context.result_as_json()?
+                                || out.as_ref().is_some_and(|out| {
+                                    let mut refs = BTreeSet::new();
+                                    out.refs(&mut refs);
+                                    refs.contains(name)
+                                });
+                            let body = offset.inc().inc();
+                            let checks = validate::checks(
+                                &format!("{name}.{}", context.rename_method(method_name)?),
+                                args,
+                                &bound,
+                                natures,
+                                context.exception_suppression()?,
+                                *asyncness,
+                                &body,
+                            )?;
+                            let args = Natures::get_fn_args_names(args);
+                            let call_exp = format!(
+                                "nativeModuleRef.{native_name}({})",
+                                bind_args(&args, &bound, natures)?
+                            );
+                            buf.write_all(
+                                format!(
+                                    "{member}{}({}) {{
+{checks}{}{member}}},\n",
+                                    context.rename_method(method_name)?,
+                                    args.join(", "),
+                                    fn_body(
+                                        call_exp,
+                                        context.exception_suppression()?,
+                                        result_as_json,
+                                        None,
+                                        context.error_as_json()?,
+                                        *asyncness,
+                                        &body,
+                                    )?,
+                                    member = offset.inc()
+                                )
+                                .as_bytes(),
+                            )?;
+                        }
+                    }
+                }
+                buf.write_all(format!("{offset}}}){semi}\n",).as_bytes())?;
+            }
+            Referred::Func(fn_name, context, nature) => {
+                let fn_name = context.rename_method(fn_name)?;
+                let bound = context.get_bound_args();
+                let wrapped = context.result_as_json()?
+                    || context.error_as_json()?
+                    || context.exception_suppression()?;
+                let body = offset.inc();
+                let checks = if let Nature::Composite(Composite::Func(_, args, ..)) = nature.deref()
+                {
+                    validate::checks(
+                        &fn_name,
+                        args,
+                        &bound,
+                        natures,
+                        context.exception_suppression()?,
+                        nature.is_fn_async()?,
+                        &body,
+                    )?
+                } else {
+                    String::new()
+                };
+                buf.write_all(
+                    format!("\n{offset}const {{ {fn_name} }} = nativeModuleRef{semi}").as_bytes(),
+                )?;
+                if bound.is_empty() && !wrapped && checks.is_empty() {
+                    buf.write_all(
+                        format!(
+                            "\n{offset}{}",
+                            export_binding(format, &fn_name, &fn_name, &emitter)
+                        )
+                        .as_bytes(),
+                    )?;
+                    return Ok(());
+                }
+                let args = nature.get_fn_args_names()?;
+                let alias = format!("$${fn_name}");
+                let call_exp = if bound.is_empty() {
+                    format!("{fn_name}({})", args.join(", "))
+                } else {
+                    format!("{fn_name}({})", bind_args(&args, &bound, natures)?)
+                };
+                buf.write_all(
+                    format!(
+                        "
+{offset}function {alias}({}) {{
+{checks}{}{offset}}}",
+                        args.join(", "),
+                        fn_body(
+                            call_exp,
+                            context.exception_suppression()?,
+                            context.result_as_json()?,
+                            None,
+                            context.error_as_json()?,
+                            nature.is_fn_async()?,
+                            &body,
+                        )?
+                    )
+                    .as_bytes(),
+                )?;
+                buf.write_all(
+                    format!(
+                        "\n{offset}{}\n",
+                        export_binding(format, &fn_name, &alias, &emitter)
+                    )
+                    .as_bytes(),
+                )?;
+            }
+            Referred::Constant(name, _context, _ty, value) => {
+                buf.write_all(
+                    format!(
+                        "{offset}{}{}{semi}\n",
+                        export_assign(format, name),
+                        emitter.expression(value)
+                    )
+                    .as_bytes(),
+                )?;
+            }
+            _ => {
+                return Err(E::Parsing(
+                    "Given nature cannot be declared for JS".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    config,
+    error::E,
+    interpreter::{guard::Interpreter, Emitter, Offset},
+    nature::{Composite, Nature, Natures, Primitive, Referred, TypeAsString},
+};
+
+/// Returns bounds of integer types (`u8` → `0..=255`). Integers, which are passed as `number`,
+/// are limited by safe integers; floats aren't limited.
+fn bounds(ty: &str, bigint: bool) -> Option<(&'static str, &'static str)> {
+    Some(match (ty, bigint) {
+        ("u8", false) => ("0", "255"),
+        ("u16", false) => ("0", "65535"),
+        ("u32", false) => ("0", "4294967295"),
+        ("i8", false) => ("-128", "127"),
+        ("i16", false) => ("-32768", "32767"),
+        ("i32", false) => ("-2147483648", "2147483647"),
+        ("u64" | "usize", false) => ("0", "Number.MAX_SAFE_INTEGER"),
+        ("i64" | "isize", false) => ("Number.MIN_SAFE_INTEGER", "Number.MAX_SAFE_INTEGER"),
+        ("u64" | "usize", true) => ("0n", "18446744073709551615n"),
+        ("i64" | "isize", true) => ("-9223372036854775808n", "9223372036854775807n"),
+        ("u128", true) => ("0n", "340282366920938463463374607431768211455n"),
+        ("i128", true) => (
+            "-170141183460469231731687303715884105728n",
+            "170141183460469231731687303715884105727n",
+        ),
+        _ => return None,
+    })
+}
+
+/// Returns the check of an argument. Integers are checked with bounds of their types, optional
+/// arguments can be omitted; other types are checked with type guards (see `guard`).
+fn check(nature: &Nature, natures: &Natures, emitter: &Emitter, value: &str) -> Result<String, E> {
+    Ok(match nature {
+        Nature::Primitive(Primitive::Number(ty)) => {
+            match bounds(&ty.type_as_string()?, false) {
+                Some((min, max)) => format!(
+                    "(typeof {value} === {} && Number.isInteger({value}) && {value} >= {min} && {value} <= {max})",
+                    emitter.str("number")
+                ),
+                None => nature.guard(natures, emitter, value, false)?,
+            }
+        }
+        Nature::Primitive(Primitive::BigInt(ty)) => match bounds(&ty.type_as_string()?, true) {
+            Some((min, max)) => format!(
+                "(typeof {value} === {} && {value} >= {min} && {value} <= {max})",
+                emitter.str("bigint")
+            ),
+            None => nature.guard(natures, emitter, value, false)?,
+        },
+        Nature::Composite(Composite::Option(_, Some(ty))) => format!(
+            "({value} === null || {value} === undefined || {})",
+            check(ty, natures, emitter, value)?
+        ),
+        Nature::Composite(Composite::Vec(_, Some(ty))) | Nature::Composite(Composite::Array(ty)) => {
+            format!(
+                "(Array.isArray({value}) && {value}.every((item) => {}))",
+                check(ty, natures, emitter, "item")?
+            )
+        }
+        _ => nature.guard(natures, emitter, value, false)?,
+    })
+}
+
+/// Returns the expected type of an argument for error messages (`u8`, `Vec<User>`).
+fn expected(nature: &Nature) -> String {
+    let ty = nature
+        .type_as_string()
+        .unwrap_or_else(|_| String::from("value"));
+    // Origin types are rendered from tokens ("Vec < u8 >")
+    let chars = ty.chars().collect::<Vec<char>>();
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| {
+            let word = |c: Option<&char>| c.is_some_and(|c| c.is_alphanumeric() || *c == '_');
+            **c != ' ' || (word(chars.get(i.wrapping_sub(1))) && word(chars.get(i + 1)))
+        })
+        .map(|(_, c)| c)
+        .collect()
+}
+
+/// Returns the negation of the check (`!(typeof a === "number")`, `!isUser(a)`). Conditions
+/// wrapped in parentheses and calls aren't wrapped once again.
+fn negate(condition: &str) -> String {
+    let mut depth = 0;
+    let closing = condition.char_indices().find_map(|(i, c)| {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        None
+    });
+    let head = &condition[..condition.find('(').unwrap_or(condition.len())];
+    if closing == Some(condition.len() - 1)
+        && head
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    {
+        format!("!{condition}")
+    } else {
+        format!("!({condition})")
+    }
+}
+
+/// Returns statements, which check arguments of a function or a method before the call of the
+/// native module (`validate_args = true`); otherwise an empty string.
+///
+/// Arguments bound to JSON (`data = "User"`) are checked with the type guard of the bound
+/// entity. An invalid argument is rejected with a `TypeError`, which names the function and
+/// the argument; the error is returned instead of thrown for async functions (as a rejected
+/// `Promise`) and with exception suppression.
+///
+/// # Arguments
+/// - `fn_name`: The name of the function in messages (`sum`, `Calculator.add`).
+/// - `args`: Arguments of the function (`FuncArg`).
+/// - `bound`: Arguments bound to JSON and names of bound entities.
+/// - `natures`: All known entities.
+/// - `returning`: `true` if the error should be returned instead of thrown.
+/// - `asyncness`: `true` if the function is async.
+/// - `offset`: Indentation of statements of the function body.
+///
+/// # Errors
+/// Returns an error if some type of arguments cannot be checked.
+pub fn checks(
+    fn_name: &str,
+    args: &[Nature],
+    bound: &[(String, String)],
+    natures: &Natures,
+    returning: bool,
+    asyncness: bool,
+    offset: &Offset,
+) -> Result<String, E> {
+    if !config::get()?.validate_args {
+        return Ok(String::new());
+    }
+    let emitter = offset.emitter();
+    let semi = emitter.semi();
+    let mut buf = String::new();
+    for arg in args.iter() {
+        let (name, nature) = match arg {
+            Nature::Referred(Referred::FuncArg(name, _, nature, _)) => (name, nature),
+            _ => continue,
+        };
+        let (condition, expected) = match bound.iter().find(|(arg, _)| arg == name) {
+            Some((_, entity)) => (
+                Nature::Referred(Referred::Ref(entity.to_owned(), None))
+                    .guard(natures, &emitter, name, false)?,
+                entity.to_owned(),
+            ),
+            None => (check(nature, natures, &emitter, name)?, expected(nature)),
+        };
+        let err = format!(
+            "new TypeError(`Invalid argument \"{name}\" of \"{fn_name}\": expected {expected}`)"
+        );
+        let reject = if asyncness {
+            format!("return Promise.reject({err}){semi}")
+        } else if returning {
+            format!("return {err}{semi}")
+        } else {
+            format!("throw {err}{semi}")
+        };
+        buf.push_str(&format!(
+            "{offset}if ({}) {{\n{}{reject}\n{offset}}}\n",
+            negate(&condition),
+            offset.inc()
+        ));
+    }
+    Ok(buf)
+}
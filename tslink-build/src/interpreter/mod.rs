@@ -0,0 +1,142 @@
+pub(crate) mod dts;
+pub(crate) mod emitter;
+pub(crate) mod guard;
+pub(crate) mod header;
+pub(crate) mod js;
+pub(crate) mod offset;
+pub(crate) mod output;
+pub(crate) mod schema;
+pub(crate) mod ts;
+pub(crate) mod zod;
+
+use crate::{
+    config::Config,
+    context::Target,
+    error::E,
+    nature::{Nature, Natures},
+    package, registry, JOURNAL,
+};
+use convert_case::{Case, Casing};
+pub use emitter::*;
+pub use offset::*;
+pub use output::*;
+use std::path::Path;
+
+pub fn serialize_name<S: AsRef<str>>(s: S) -> String {
+    if s.as_ref().starts_with("r#") {
+        s.as_ref().replace("r#", "")
+    } else {
+        s.as_ref().to_string()
+    }
+}
+
+/// Returns the name of the function, which exports a method of an enum from the native module
+/// (`level_label` for `Level::label`).
+pub fn enum_method_name(enum_name: &str, method: &str) -> String {
+    format!("{}_{method}", enum_name.to_case(Case::Snake))
+}
+
+/// Returns the path of `to` relative to the folder `from` with `/` as separator
+/// (both paths are relative to the current folder), e.g. `../dist/index.node`.
+///
+/// # Errors
+/// Returns an error if the current folder cannot be detected.
+pub fn relative_path(from: &Path, to: &Path) -> Result<String, E> {
+    let from = absolute(from)?;
+    let to = absolute(to)?;
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut parts = vec![String::from(".."); from.components().count() - common];
+    parts.extend(
+        to.components()
+            .skip(common)
+            .map(|c| c.as_os_str().to_string_lossy().to_string()),
+    );
+    Ok(parts.join("/"))
+}
+
+/// Returns the module specifier of `to` for a module in the folder `from`: the relative path
+/// starting with `./` or `../` (e.g. `./module_a`, `../models/user`).
+///
+/// # Errors
+/// Returns an error if the current folder cannot be detected.
+pub fn specifier(from: &Path, to: &Path) -> Result<String, E> {
+    let path = relative_path(from, to)?;
+    Ok(if path.starts_with("../") {
+        path
+    } else {
+        format!("./{path}")
+    })
+}
+
+/// Renders the entity into the file of the given kind (`#[tslink(target = "...")]`):
+/// `*.ts` module, standalone `*.d.ts` declaration or `*.js` runtime module.
+///
+/// # Errors
+/// Returns an error if rendering fails.
+fn create_target_file(
+    entity: &Nature,
+    natures: &Natures,
+    target: &Target,
+    path: &Path,
+    output: &mut Output,
+) -> Result<(), E> {
+    match target {
+        Target::Ts => {
+            let mut writer = ts::Writer::new(path.to_owned(), output.get(path), Emitter::get()?);
+            ts::write(entity, natures, &mut writer, output)
+        }
+        Target::DTs => dts::write_item(entity, natures, path, output),
+        Target::Js => js::write_item(entity, natures, path, output),
+    }
+}
+
+/// Writes files of per-item targets (`#[tslink(target = "./a.ts; ./b.d.ts; ./c.js")]`)
+/// and `index.ts` files of modules.
+pub fn targets(natures: &Natures) -> Result<(), E> {
+    let mut output = Output::default();
+    for (_name, entity) in natures.iter() {
+        for (target, path) in entity.get_context()?.targets.iter() {
+            create_target_file(entity, natures, target, path, &mut output)?;
+        }
+    }
+    output.write()?;
+    ts::Indexer::new(natures)?.write()
+}
+
+pub fn dts(natures: &Natures) -> Result<(), E> {
+    let mut output = Output::default();
+    for (_name, entity) in natures.iter() {
+        dts::write(entity, natures, &mut output)?;
+    }
+    output.write()
+}
+
+pub fn js(natures: &Natures) -> Result<(), E> {
+    js::write(natures)
+}
+
+/// Generates all artifacts for the given entities: files of per-item targets, the JSON Schema
+/// bundle (if configured) and, if the node module is configured, `package.json`, `lib.d.ts`
+/// and `lib.js`. Paths of generated files are recorded in the registry of the crate (not in
+/// check mode).
+///
+/// # Errors
+/// Returns an error if rendering or writing of any file fails.
+pub fn generate(natures: &Natures, cfg: &Config) -> Result<(), E> {
+    targets(natures)?;
+    schema::write(natures)?;
+    if cfg.node_mod_filename.is_some() {
+        package::create()?;
+        dts(natures)?;
+        js(natures)?;
+    }
+    let journal = JOURNAL.read().map_err(|e| E::AccessError(e.to_string()))?;
+    if !journal.check {
+        registry::record_files(&journal.files)?;
+    }
+    Ok(())
+}
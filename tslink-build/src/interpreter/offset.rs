@@ -0,0 +1,33 @@
+use crate::interpreter::Emitter;
+use std::fmt;
+
+/// Indentation of rendered code. Offsets are created by [`Emitter::offset`], so the emitter
+/// is available everywhere code is rendered.
+#[derive(Debug, Clone)]
+pub struct Offset {
+    tab: usize,
+    emitter: Emitter,
+}
+
+impl Offset {
+    pub fn new(emitter: Emitter) -> Self {
+        Self { tab: 0, emitter }
+    }
+
+    pub fn inc(&self) -> Self {
+        Self {
+            tab: self.tab + 1,
+            emitter: self.emitter,
+        }
+    }
+
+    pub fn emitter(&self) -> Emitter {
+        self.emitter
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.emitter.indent(self.tab))
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    config,
+    error::E,
+    interpreter::{header, Emitter},
+    JOURNAL,
+};
+use std::{
+    fs::{self, create_dir_all},
+    path::{Component, Path, PathBuf},
+    process,
+};
+
+/// Collects the content of generated files in memory.
+///
+/// All files are rendered completely before touching the file system. After that each
+/// file is written only if its content has been changed (see [`write_if_changed`]), so
+/// watchers (`tsc --watch`, bundlers, test runners) never see partially written files.
+#[derive(Default)]
+pub struct Output {
+    files: Vec<(PathBuf, String)>,
+}
+
+impl Output {
+    /// Appends content to the file with the given path.
+    pub fn append<S: AsRef<str>>(&mut self, path: &Path, content: S) {
+        if let Some((_, buffer)) = self.files.iter_mut().find(|(p, _)| p == path) {
+            buffer.push_str(content.as_ref());
+        } else {
+            self.files
+                .push((path.to_path_buf(), content.as_ref().to_owned()));
+        }
+    }
+
+    /// Returns the content rendered so far for the file with the given path.
+    pub fn get(&self, path: &Path) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, content)| content.as_str())
+    }
+
+    /// Writes all collected files to the file system (see [`emit_code`]).
+    ///
+    /// # Errors
+    /// Returns an error if any file cannot be written.
+    pub fn write(&self) -> Result<(), E> {
+        for (path, content) in self.files.iter() {
+            emit_code(path, content)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the absolute path for the given path (relative to the output folder, which is the
+/// current folder by default) without `.` and `..` components.
+///
+/// # Errors
+/// Returns an error if the output folder cannot be detected.
+pub fn absolute(path: &Path) -> Result<PathBuf, E> {
+    let mut normalized = PathBuf::new();
+    for component in config::output_dir()?.join(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    Ok(normalized)
+}
+
+/// Journal of generated files.
+#[derive(Default)]
+pub struct Journal {
+    /// If `true`, generated files are only compared with existing files and aren't written.
+    pub check: bool,
+    /// Paths of all generated files.
+    pub files: Vec<PathBuf>,
+    /// Paths of files, which content differs from generated content.
+    pub changed: Vec<PathBuf>,
+}
+
+impl Journal {
+    /// Returns `true` if generated files are only compared with existing files.
+    ///
+    /// # Errors
+    /// Returns an error if the journal cannot be accessed.
+    pub fn is_check() -> Result<bool, E> {
+        Ok(JOURNAL
+            .read()
+            .map_err(|e| E::AccessError(e.to_string()))?
+            .check)
+    }
+}
+
+/// Emits a generated file: registers it in the journal and writes it (see [`write_if_changed`]).
+/// In check mode the file isn't written; if its content differs, it's registered as changed.
+///
+/// Returns `true` if the file has been written.
+///
+/// # Errors
+/// Returns an error if the journal cannot be accessed or the file cannot be written.
+pub fn emit(path: &Path, content: &str) -> Result<bool, E> {
+    let file = absolute(path)?;
+    let mut journal = JOURNAL.write().map_err(|e| E::AccessError(e.to_string()))?;
+    if !journal.files.iter().any(|p| p == path) {
+        journal.files.push(path.to_path_buf());
+    }
+    if journal.check {
+        if !fs::read_to_string(&file).is_ok_and(|current| current == content)
+            && !journal.changed.iter().any(|p| p == path)
+        {
+            journal.changed.push(path.to_path_buf());
+        }
+        Ok(false)
+    } else {
+        write_if_changed(&file, content)
+    }
+}
+
+/// Removes a previously generated file, which isn't generated anymore. In check mode the file
+/// isn't removed; it's registered as changed.
+///
+/// # Errors
+/// Returns an error if the journal cannot be accessed or the file cannot be removed.
+pub fn discard(path: &Path) -> Result<(), E> {
+    let file = absolute(path)?;
+    if !file.exists() {
+        return Ok(());
+    }
+    let mut journal = JOURNAL.write().map_err(|e| E::AccessError(e.to_string()))?;
+    if !journal.check {
+        fs::remove_file(&file)?;
+    } else if !journal.changed.iter().any(|p| p == path) {
+        journal.changed.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Emits a generated `*.ts`, `*.d.ts` or `*.js` file with the header of tslink and line endings
+/// of the code style (see [`emit`]).
+///
+/// # Errors
+/// Returns an error if the configuration cannot be accessed, the file cannot be written or
+/// the existing file has been modified manually (unless `force_overwrite` is set).
+pub fn emit_code(path: &Path, content: &str) -> Result<bool, E> {
+    let config = config::get()?;
+    let emitter = Emitter::new(config.style);
+    let content = emitter.finish(content.to_owned());
+    let content = format!(
+        "{}{content}",
+        emitter.finish(header::render(&config, &content)?)
+    );
+    if !config.force_overwrite && !Journal::is_check()? && header::is_modified(&absolute(path)?) {
+        return Err(E::ModifiedManually(path.display().to_string()));
+    }
+    emit(path, &content)
+}
+
+/// Writes the content into the file only if it differs from the current content of the file.
+///
+/// The content is written into a temporary file next to the destination first; the temporary
+/// file then replaces the destination by renaming, which makes the replacement atomic.
+///
+/// Returns `true` if the file has been written.
+///
+/// # Errors
+/// Returns an error if the destination folder cannot be created or the file cannot be written.
+pub fn write_if_changed(path: &Path, content: &str) -> Result<bool, E> {
+    if fs::read_to_string(path).is_ok_and(|current| current == content) {
+        return Ok(false);
+    }
+    let (basepath, file_name) =
+        if let (Some(basepath), Some(file_name)) = (path.parent(), path.file_name()) {
+            (basepath, file_name)
+        } else {
+            return Err(E::FileNotFound(format!(
+                "Fail to get basepath from: {}",
+                path.to_string_lossy()
+            )));
+        };
+    if !basepath.exists() {
+        create_dir_all(basepath)?;
+    }
+    let tmp = basepath.join(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        process::id()
+    ));
+    fs::write(&tmp, content)?;
+    if let Err(err) = fs::rename(&tmp, path) {
+        let _ = fs::remove_file(&tmp);
+        return Err(err.into());
+    }
+    Ok(true)
+}
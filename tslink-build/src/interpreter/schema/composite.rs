@@ -0,0 +1,73 @@
+use super::{tuple, Interpreter};
+use crate::{
+    error::E,
+    nature::{Composite, Nature, Natures},
+};
+use serde_json::{json, Value as Json};
+
+/// JSON Schema of composite types.
+///
+/// - `Array`, `Vec` → `array` with `items`
+/// - `HashMap` → `object` with `additionalProperties`
+/// - `Tuple` → `array` with `prefixItems`
+/// - `Option` → `anyOf` of the type and `null`
+/// - `Result` → `oneOf` of `{ "Ok": ... }` and `{ "Err": ... }` (default `serde` representation)
+/// - `Undefined` → `null`
+/// - `Func` → not supported, functions cannot be represented in JSON
+impl Interpreter for Composite {
+    fn schema(&self, natures: &Natures) -> Result<Json, E> {
+        Ok(match self {
+            Self::Array(ty) => json!({ "type": "array", "items": ty.schema(natures)? }),
+            Self::Vec(_, ty) => {
+                if let Some(ty) = ty {
+                    json!({ "type": "array", "items": ty.schema(natures)? })
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type Vec doesn't include reference to type",
+                    )));
+                }
+            }
+            Self::HashMap(_, _, ty) => {
+                if let Some(ty) = ty {
+                    json!({ "type": "object", "additionalProperties": ty.schema(natures)? })
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type HashMap doesn't include reference to type",
+                    )));
+                }
+            }
+            Self::Tuple(_, tys) => tuple(natures, tys)?,
+            Self::Option(_, ty) => {
+                if let Some(ty) = ty {
+                    json!({ "anyOf": [ty.schema(natures)?, { "type": "null" }] })
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type Option doesn't include reference to type",
+                    )));
+                }
+            }
+            Self::Result(_, res, err, ..) => {
+                let variant = |name: &str, ty: &Option<Box<Nature>>| -> Result<Json, E> {
+                    let ty = if let Some(ty) = ty {
+                        ty.schema(natures)?
+                    } else {
+                        json!({ "type": "null" })
+                    };
+                    Ok(json!({
+                        "type": "object",
+                        "properties": { name: ty },
+                        "required": [name],
+                        "additionalProperties": false,
+                    }))
+                };
+                json!({ "oneOf": [variant("Ok", res)?, variant("Err", err)?] })
+            }
+            Self::Undefined(..) => json!({ "type": "null" }),
+            Self::Func(..) => {
+                return Err(E::NotSupported(String::from(
+                    "Functions cannot be described with JSON Schema",
+                )))
+            }
+        })
+    }
+}
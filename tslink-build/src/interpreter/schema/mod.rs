@@ -0,0 +1,89 @@
+mod composite;
+mod primitive;
+mod refered;
+
+use crate::{
+    config,
+    error::E,
+    interpreter::emit,
+    nature::{Nature, Natures, Referred},
+};
+use serde::Serialize;
+use serde_json::{json, ser::PrettyFormatter, Map, Serializer, Value as Json};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Trait for describing the JSON representation of a given type (`Nature`) with JSON Schema
+/// (draft 2020-12).
+///
+/// Schemas describe the same shapes as the generated TypeScript types; entities are referred
+/// with `{ "$ref": "#/$defs/Name" }`.
+pub trait Interpreter {
+    /// Returns the schema of the type.
+    fn schema(&self, natures: &Natures) -> Result<Json, E>;
+}
+
+impl Interpreter for Nature {
+    /// Delegates rendering to the specific variant: `Primitive`, `Composite`, or `Referred`.
+    fn schema(&self, natures: &Natures) -> Result<Json, E> {
+        match self {
+            Self::Primitive(primitive) => primitive.schema(natures),
+            Self::Composite(composite) => composite.schema(natures),
+            Self::Referred(refered) => refered.schema(natures),
+        }
+    }
+}
+
+/// Returns the schema of a fixed-length array (tuple) with the given items.
+fn tuple(natures: &Natures, items: &[Nature]) -> Result<Json, E> {
+    let items = items
+        .iter()
+        .map(|item| item.schema(natures))
+        .collect::<Result<Vec<Json>, E>>()?;
+    Ok(json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": items.len(),
+    }))
+}
+
+/// Writes the JSON Schema bundle (`json_schema = "./dist/schema.json"`): a single document
+/// with schemas of all structs, enums, tuple structs and constants in `$defs`.
+///
+/// Nothing is written if the path to the bundle isn't configured.
+///
+/// # Errors
+/// Returns an error if some type cannot be described or the file cannot be written.
+pub fn write(natures: &Natures) -> Result<(), E> {
+    let config = config::get()?;
+    let path = match config.json_schema.as_ref() {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+    let mut defs = Map::new();
+    for (name, nature) in natures.iter() {
+        if matches!(
+            nature,
+            Nature::Referred(
+                Referred::Struct(..)
+                    | Referred::TupleStruct(..)
+                    | Referred::Enum(..)
+                    | Referred::Constant(..)
+            )
+        ) {
+            defs.insert(name.to_owned(), nature.schema(natures)?);
+        }
+    }
+    let bundle = json!({
+        "$schema": DRAFT,
+        "title": config.package_name()?,
+        "$defs": defs,
+    });
+    let mut buf = Vec::new();
+    let mut serializer =
+        Serializer::with_formatter(&mut buf, PrettyFormatter::with_indent(b"    "));
+    bundle.serialize(&mut serializer)?;
+    emit(path, &String::from_utf8_lossy(&buf))?;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use super::Interpreter;
+use crate::{
+    error::E,
+    nature::{Natures, Primitive, TypeAsString},
+};
+use serde_json::{json, Value as Json};
+
+/// JSON Schema of primitive types.
+///
+/// - `Number` → `integer` for integer types and `number` for floats; integers up to 32 bits are
+///   limited with `minimum` and `maximum` of the type, other unsigned integers with `minimum: 0`
+/// - `BigInt` → `integer` (`serde_json` serializes 64- and 128-bit integers as numbers)
+/// - `String` → `string`
+/// - `Boolean` → `boolean`
+impl Interpreter for Primitive {
+    fn schema(&self, _natures: &Natures) -> Result<Json, E> {
+        Ok(match self {
+            Self::Number(ty) | Self::BigInt(ty) => {
+                let ty = ty.type_as_string()?;
+                match ty.as_str() {
+                    "u8" => json!({ "type": "integer", "minimum": 0, "maximum": u8::MAX }),
+                    "u16" => json!({ "type": "integer", "minimum": 0, "maximum": u16::MAX }),
+                    "u32" => json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX }),
+                    "i8" => json!({ "type": "integer", "minimum": i8::MIN, "maximum": i8::MAX }),
+                    "i16" => {
+                        json!({ "type": "integer", "minimum": i16::MIN, "maximum": i16::MAX })
+                    }
+                    "i32" => {
+                        json!({ "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX })
+                    }
+                    _ if ty.starts_with('f') => json!({ "type": "number" }),
+                    _ if ty.starts_with('u') => json!({ "type": "integer", "minimum": 0 }),
+                    _ => json!({ "type": "integer" }),
+                }
+            }
+            Self::String(..) => json!({ "type": "string" }),
+            Self::Boolean(..) => json!({ "type": "boolean" }),
+        })
+    }
+}
@@ -0,0 +1,210 @@
+use super::{tuple, Interpreter};
+use crate::{
+    config::cfg::EnumRepresentation,
+    error::E,
+    nature::{Composite, Nature, Natures, Referred},
+};
+use serde_json::{json, Map, Value as Json};
+use std::ops::Deref;
+use syn::{Expr, ExprArray, ExprGroup, ExprLit, ExprParen, ExprReference, ExprUnary, Lit, UnOp};
+
+/// Returns the schema of an object with the given named fields; all fields are required,
+/// because `serde` serializes `None` as `null`. Methods and ignored fields are skipped.
+fn object(natures: &Natures, fields: &[Nature]) -> Result<Json, E> {
+    let mut properties = Map::new();
+    for field in fields.iter() {
+        if let Nature::Referred(Referred::Field(name, context, nature, _)) = field {
+            if matches!(nature.deref(), Nature::Composite(Composite::Func(..)))
+                || field.is_field_ignored()
+            {
+                continue;
+            }
+            properties.insert(context.rename_field(name)?, field.schema(natures)?);
+        }
+    }
+    let required: Vec<String> = properties.keys().cloned().collect();
+    Ok(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    }))
+}
+
+/// Returns the schema of an object with the single property `name`.
+fn tagged(name: &str, value: Json) -> Json {
+    json!({
+        "type": "object",
+        "properties": { name: value },
+        "required": [name],
+        "additionalProperties": false,
+    })
+}
+
+/// Returns the JSON value of the expression of a constant (literals, negative numbers, arrays
+/// and references to them); `None` if the expression cannot be evaluated.
+fn constant(expr: &Expr) -> Option<Json> {
+    Some(match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Int(int) => int
+                .base10_parse::<u64>()
+                .map(Json::from)
+                .or_else(|_| int.base10_parse::<i64>().map(Json::from))
+                .ok()?,
+            Lit::Float(float) => Json::from(float.base10_parse::<f64>().ok()?),
+            Lit::Str(str) => Json::from(str.value()),
+            Lit::Char(char) => Json::from(char.value().to_string()),
+            Lit::Bool(bool) => Json::from(bool.value),
+            _ => return None,
+        },
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(..),
+            expr,
+            ..
+        }) => match constant(expr)? {
+            Json::Number(number) => {
+                if let Some(int) = number.as_u64() {
+                    Json::from(i64::try_from(-i128::from(int)).ok()?)
+                } else {
+                    Json::from(-number.as_f64()?)
+                }
+            }
+            _ => return None,
+        },
+        Expr::Array(ExprArray { elems, .. }) => {
+            Json::Array(elems.iter().map(constant).collect::<Option<Vec<Json>>>()?)
+        }
+        Expr::Reference(ExprReference { expr, .. })
+        | Expr::Paren(ExprParen { expr, .. })
+        | Expr::Group(ExprGroup { expr, .. }) => constant(expr)?,
+        _ => return None,
+    })
+}
+
+/// JSON Schema of named entities.
+///
+/// - `Struct` → `object` with all fields required (methods are skipped)
+/// - `TupleStruct` → schema of the inner type (`null` for unit structs)
+/// - `Enum` → depends on variants and `EnumRepresentation`:
+///   - flat (no data in variants) → `string` with `enum` of variant names
+///   - variants without data are `const` strings in all representations (as serialized by `serde`)
+///   - `Flat` → `object` with a single property named after a variant with data
+///   - `Union`, `DiscriminatedUnion` → `oneOf` of objects with a single property named after
+///     a variant with data
+/// - `EnumVariant` → data of the variant: `null`, the single type, a tuple or an object
+/// - `Field` → schema of its type (generic parameters are resolved)
+/// - `Constant` → schema of its type with `const` value (if the value is a literal)
+/// - `Ref` → `{ "$ref": "#/$defs/Name" }` for known entities and `{}` (any value) for others
+/// - `Func`, `FuncArg`, `Generic` → not supported
+impl Interpreter for Referred {
+    fn schema(&self, natures: &Natures) -> Result<Json, E> {
+        Ok(match self {
+            Referred::Struct(_, _, fields) => object(natures, fields)?,
+            Referred::TupleStruct(_, _, field) => {
+                if let Some(field) = field {
+                    field.schema(natures)?
+                } else {
+                    json!({ "type": "null" })
+                }
+            }
+            Referred::Enum(_, _, variants, repres, _) => {
+                let mut payloads = Vec::new();
+                for variant in variants.iter() {
+                    if let Nature::Referred(Referred::EnumVariant(name, _, fields, ..)) = variant {
+                        payloads.push((name, fields.is_empty(), variant.schema(natures)?));
+                    }
+                }
+                if Referred::is_flat_varians(variants)? {
+                    let names: Vec<&String> = payloads.iter().map(|(name, ..)| *name).collect();
+                    json!({ "type": "string", "enum": names })
+                } else {
+                    // serde serializes variants without data as strings in all representations
+                    let mut one_of: Vec<Json> = payloads
+                        .iter()
+                        .filter(|(_, unit, _)| *unit)
+                        .map(|(name, ..)| json!({ "const": name }))
+                        .collect();
+                    let payloads = payloads.into_iter().filter(|(_, unit, _)| !unit);
+                    match repres {
+                        EnumRepresentation::Flat => {
+                            let mut properties = Map::new();
+                            for (name, _, payload) in payloads {
+                                properties.insert(name.to_owned(), payload);
+                            }
+                            one_of.push(json!({
+                                "type": "object",
+                                "properties": properties,
+                                "minProperties": 1,
+                                "maxProperties": 1,
+                                "additionalProperties": false,
+                            }));
+                        }
+                        EnumRepresentation::Union | EnumRepresentation::DiscriminatedUnion => {
+                            one_of.extend(payloads.map(|(name, _, payload)| tagged(name, payload)));
+                        }
+                    }
+                    if one_of.len() == 1 {
+                        one_of.remove(0)
+                    } else {
+                        json!({ "oneOf": one_of })
+                    }
+                }
+            }
+            Referred::EnumVariant(_, _, fields, ..) => {
+                if fields.is_empty() {
+                    json!({ "type": "null" })
+                } else if fields
+                    .iter()
+                    .any(|f| matches!(f, Nature::Referred(Referred::Field(..))))
+                {
+                    object(natures, fields)?
+                } else if fields.len() == 1 {
+                    fields
+                        .first()
+                        .ok_or(E::Parsing(String::from(
+                            "Expecting single field for Variant",
+                        )))?
+                        .schema(natures)?
+                } else {
+                    tuple(natures, fields)?
+                }
+            }
+            Referred::Field(_, context, nature, _) => {
+                if let Nature::Referred(Referred::Ref(ref_name, _)) = nature.deref() {
+                    if let Some(generic) = context.get_generic(ref_name) {
+                        return generic.schema(natures);
+                    }
+                }
+                nature.schema(natures)?
+            }
+            Referred::Constant(_, _, ty, value) => {
+                let mut schema = ty.schema(natures)?;
+                // Values of expressions (`2 * 5`) aren't evaluated, so only the type is described
+                if let Some(value) = syn::parse_str::<Expr>(value)
+                    .ok()
+                    .and_then(|expr| constant(&expr))
+                {
+                    schema["const"] = value;
+                }
+                schema
+            }
+            Referred::Ref(ref_name, ..) => {
+                if natures.contains(ref_name) {
+                    json!({ "$ref": format!("#/$defs/{ref_name}") })
+                } else {
+                    json!({})
+                }
+            }
+            Referred::Func(name, ..) | Referred::FuncArg(name, ..) => {
+                return Err(E::NotSupported(format!(
+                    "Function {name} cannot be described with JSON Schema"
+                )));
+            }
+            Referred::Generic(alias, ..) => {
+                return Err(E::Parsing(format!(
+                    "Generic type cannot be rendered out of context; type alias = {alias}"
+                )))
+            }
+        })
+    }
+}
@@ -27,6 +27,7 @@ use crate::{
 ///   → Emits a function signature like `(arg1: T, arg2: U) => V` or `(...): Promise<V>` if async.  
 ///   → If marked as a constructor and contains generic args, an error is returned (unsupported case).  
 ///   → If it's a constructor, emits `(...)` only (no return).
+///   → If it's a static method, emits `(...) => V`, because it's rendered as a function-typed static property.
 ///
 /// - `Tuple`  
 ///   → Emits `[T1, T2, T3]`.
@@ -89,7 +90,7 @@ impl Interpreter for Composite {
                     )));
                 }
             }
-            Self::Func(_, args, out, asyncness, constructor) => {
+            Self::Func(_, args, out, asyncness, constructor, static_method) => {
                 buf.push("(");
                 let mut generic = false;
                 for (i, nature) in args.iter().enumerate() {
@@ -114,7 +115,14 @@ impl Interpreter for Composite {
                     buf.push(")");
                     return Ok(());
                 }
-                buf.push(format!("){} ", if generic { " =>" } else { ":" }));
+                buf.push(format!(
+                    "){} ",
+                    if generic || *static_method {
+                        " =>"
+                    } else {
+                        ":"
+                    }
+                ));
                 if *asyncness {
                     buf.push("Promise<");
                 }
@@ -0,0 +1,34 @@
+use crate::interpreter::Emitter;
+
+/// Represents a TypeScript export statement for a specific entity from a module.
+///
+/// This structure is used to track and render TypeScript export declarations like:
+/// `export { MyClass } from "./my_module";` or `export type { MyInterface } from "./my_module";`
+///
+/// # Fields
+/// - `entity`: The name of the exported item (e.g., a class, function, or type).
+/// - `specifier`: The module specifier relative to the `index.ts` (e.g., `./my_module`).
+/// - `type_only`: `true` if the item exists only as type (interface, type alias).
+///
+/// # Usage
+/// This struct is typically collected during code generation to emit final export blocks.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Export {
+    pub entity: String,
+    pub specifier: String,
+    pub type_only: bool,
+}
+
+impl Export {
+    /// Renders the TypeScript export line in the following format:
+    /// `export { EntityName } from "./module_name";` (`export type { ... }` for type-only items)
+    pub fn render(&self, emitter: &Emitter) -> String {
+        format!(
+            "export {}{{ {} }} from {}{}",
+            if self.type_only { "type " } else { "" },
+            self.entity,
+            emitter.str(&self.specifier),
+            emitter.semi()
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use crate::interpreter::Emitter;
+
+/// Represents a TypeScript import statement for a specific entity from a module.
+///
+/// This structure is used to generate TypeScript import declarations like:
+/// `import type { MyClass } from "./my_module";`
+///
+/// Entities are referenced in `*.ts` files only as types, so `import type` is used for them;
+/// values (zod and zod schemas of entities) are imported with `import { ... }`.
+///
+/// # Fields
+/// - `entity`: The name of the imported item (e.g., a class, function, or type).
+/// - `specifier`: The module specifier relative to the importing file (e.g., `./my_module`,
+///   `../models/user.js`).
+/// - `type_only`: `true` if the item is used only as type.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct Import {
+    pub entity: String,
+    pub specifier: String,
+    pub type_only: bool,
+}
+
+impl Import {
+    /// Renders the TypeScript import line in the following format:
+    /// `import type { EntityName } from "./module_name";` (`import { ... }` for values)
+    pub fn render(&self, emitter: &Emitter) -> String {
+        format!(
+            "import {}{{ {} }} from {}{}",
+            if self.type_only { "type " } else { "" },
+            self.entity,
+            emitter.str(&self.specifier),
+            emitter.semi()
+        )
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    config,
+    context::Target,
+    error::E,
+    interpreter::{absolute, discard, guard, specifier, ts::Export, zod, Emitter, Journal, Output},
+    nature::{Nature, Natures, Referred},
+    registry,
+};
+
+/// Collects and manages export declarations for TypeScript index files (`index.ts`)
+/// to ensure correct module linkage when multiple structures are distributed
+/// across separate files using `tslink`.
+///
+/// This is essential for generating valid `import/export` chains when cross-referencing
+/// between types defined in different `.ts` files.
+///
+/// # Fields
+/// - `exports`: A set of all `Export` declarations associated with their target file paths.
+///   The set is ordered, so exports are written in a stable (sorted) order.
+#[derive(Default)]
+pub struct Indexer {
+    pub exports: BTreeSet<(PathBuf, Export)>,
+}
+
+impl Indexer {
+    /// Collects exports of all entities (structs, enums, type aliases), which are written into
+    /// `*.ts` files of modules (`#[tslink(target = "...", module = "...")]`).
+    ///
+    /// Entities marked with `#[tslink(no_index)]` are skipped. Interfaces, type aliases and
+    /// enums represented as types are exported with `export type`. With `zod = true` zod schemas
+    /// of entities are exported as well; with `type_guards = true` - type guards and assertions.
+    ///
+    /// # Errors
+    /// Returns an error if the context of an entity cannot be read.
+    pub fn new(natures: &Natures) -> Result<Self, E> {
+        let config = config::get()?;
+        let extension = config.import_extension;
+        let mut indexer = Indexer::default();
+        for (name, nature) in natures.iter() {
+            let type_only = match nature {
+                Nature::Referred(Referred::Struct(_, context, _)) => !context.as_class(),
+                Nature::Referred(Referred::TupleStruct(..)) => true,
+                Nature::Referred(Referred::Enum(_, _, variants, _, methods)) => {
+                    !Referred::is_flat_varians(variants)? && methods.is_empty()
+                }
+                _ => continue,
+            };
+            let context = nature.get_context()?;
+            if context.as_no_index() {
+                continue;
+            }
+            let target = context.targets.iter().find(|(t, _)| t == &Target::Ts);
+            if let (Some(_), Some((_, path))) = (natures.get_module_of(name), target) {
+                let dest = path.parent().unwrap_or(path);
+                let specifier =
+                    format!("{}{extension}", specifier(dest, &path.with_extension(""))?);
+                let mut values = Vec::new();
+                if config.zod && zod::has_schema(nature) {
+                    values.push(zod::schema_name(name));
+                }
+                if config.type_guards && guard::has_guard(nature) {
+                    let (is, assert) = guard::guard_names(name);
+                    values.extend([is, assert]);
+                }
+                for entity in values {
+                    indexer.add(
+                        dest,
+                        Export {
+                            entity,
+                            specifier: specifier.clone(),
+                            type_only: false,
+                        },
+                    );
+                }
+                indexer.add(
+                    dest,
+                    Export {
+                        entity: name.to_owned(),
+                        specifier,
+                        type_only,
+                    },
+                );
+            }
+        }
+        Ok(indexer)
+    }
+
+    /// Adds a new export to be tracked for the given target directory.
+    ///
+    /// # Arguments
+    /// - `dest`: The destination path (directory) where the `index.ts` should be written.
+    /// - `export`: The export definition (entity and module) to include.
+    ///
+    /// If the same export is added multiple times for the same destination, it will not be duplicated.
+    pub fn add<P: AsRef<Path>>(&mut self, dest: P, export: Export) {
+        self.exports.insert((dest.as_ref().to_owned(), export));
+    }
+
+    /// Writes `index.ts` files in all tracked destinations with their associated exports.
+    ///
+    /// The content of each `index.ts` is rendered from all known exports of its destination;
+    /// a file is written only if its content has been changed. Each `index.ts` also re-exports
+    /// `index.ts` files of the closest nested destinations (`export * from "./nested";`).
+    /// Specifiers get the configured `import_extension` (`./module.js`, `./nested/index.js`).
+    /// Previously generated `index.ts` files of folders without exports are removed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The destination folder does not exist.
+    /// - File operations fail.
+    pub fn write(&self) -> Result<(), E> {
+        let extension = config::get()?.import_extension;
+        let emitter = Emitter::get()?;
+        let mut output = Output::default();
+        for (dest, export) in self.exports.iter() {
+            // In check mode files aren't written, so the folder can be missing
+            if !absolute(dest)?.exists() && !Journal::is_check()? {
+                return Err(E::FileNotFound(format!(
+                    "Index dest folder isn't found: {}",
+                    dest.display()
+                )));
+            }
+            output.append(
+                &dest.join("index.ts"),
+                format!("{}\n", export.render(&emitter)),
+            );
+        }
+        let mut dests = Vec::new();
+        for (dest, _) in self.exports.iter() {
+            let location = absolute(dest)?;
+            if !dests.iter().any(|(_, l)| l == &location) {
+                dests.push((dest, location));
+            }
+        }
+        for (dest, location) in dests.iter() {
+            let parent = dests
+                .iter()
+                .filter(|(_, l)| l != location && location.starts_with(l))
+                .max_by_key(|(_, l)| l.components().count());
+            if let Some((parent, _)) = parent {
+                // With an extension (ESM resolution) folders cannot be imported, only files
+                let nested = if extension.is_empty() {
+                    specifier(parent, dest)?
+                } else {
+                    format!("{}{extension}", specifier(parent, &dest.join("index"))?)
+                };
+                output.append(
+                    &parent.join("index.ts"),
+                    format!("export * from {}{}\n", emitter.str(&nested), emitter.semi()),
+                );
+            }
+        }
+        output.write()?;
+        for file in registry::files()?.iter().filter(|file| {
+            file.file_name().is_some_and(|name| name == "index.ts")
+                && !dests.iter().any(|(_, l)| l.join("index.ts") == **file)
+        }) {
+            discard(file)?;
+        }
+        Ok(())
+    }
+}
@@ -12,10 +12,12 @@ pub use indexer::*;
 pub use writer::*;
 
 use crate::{
+    config,
     error::E,
-    interpreter::Offset,
-    nature::{Nature, Natures},
+    interpreter::{guard, zod, Offset, Output},
+    nature::{Nature, Natures, Referred, TypeAsString},
 };
+use std::collections::BTreeSet;
 
 /// Trait for converting a type representation (`Nature`, `Primitive`, `Composite`, etc.)
 /// into its corresponding TypeScript declaration and reference syntax.
@@ -103,24 +105,65 @@ impl Interpreter for Nature {
     }
 }
 
-/// Renders the full TypeScript declaration of a given `Interpreter` into the provided writer.
+/// Renders the full TypeScript declaration of a given entity into the provided writer.
 ///
 /// # Parameters
-/// - `w`: The entity implementing `Interpreter` (typically `Nature`).
+/// - `entity`: The entity to declare.
 /// - `natures`: Registry of known types for cross-referencing.
 /// - `buf_writer`: Output writer buffer.
+/// - `output`: In-memory content of generated files.
 ///
 /// # Behavior
 /// - Calls `declaration(...)` on the target.
-/// - Flushes the writer buffer at the end.
+/// - With `zod = true` renders the zod schema of the entity after the declaration and
+///   imports `z` and schemas of referred entities.
+/// - With `type_guards = true` renders the type guard and the assertion of the entity and
+///   imports guards of referred entities.
+/// - Appends the writer buffer to `output` at the end.
 ///
 /// # Errors
 /// Returns any error encountered during writing or generation.
-pub fn write<T>(w: &T, natures: &Natures, buf_writer: &mut Writer) -> Result<(), E>
-where
-    T: Interpreter,
-{
-    w.declaration(natures, buf_writer, Offset::new(), None)?;
-    buf_writer.write_all()?;
+pub fn write(
+    entity: &Nature,
+    natures: &Natures,
+    buf_writer: &mut Writer,
+    output: &mut Output,
+) -> Result<(), E> {
+    let emitter = buf_writer.emitter();
+    entity.declaration(natures, buf_writer, emitter.offset(), None)?;
+    let config = config::get()?;
+    if config.zod || config.type_guards {
+        let name = entity.type_as_string()?;
+        let mut refs = BTreeSet::new();
+        match entity {
+            // Methods of enums aren't rendered into modules
+            Nature::Referred(Referred::Enum(_, _, variants, ..)) => {
+                variants.iter().for_each(|variant| variant.refs(&mut refs))
+            }
+            entity => entity.refs(&mut refs),
+        }
+        if config.zod {
+            if let Some(schema) = zod::declaration(&name, entity, natures, &emitter, true)? {
+                buf_writer.add_zod_import();
+                for name in refs.iter() {
+                    if natures.get_entity(name).is_some_and(zod::has_schema) {
+                        buf_writer.add_value_import(natures, name, &zod::schema_name(name))?;
+                    }
+                }
+                buf_writer.push(schema);
+            }
+        }
+        if config.type_guards {
+            if let Some(guards) = guard::declaration(&name, entity, natures, &emitter, true)? {
+                for name in refs.iter() {
+                    if natures.get_entity(name).is_some_and(guard::has_guard) {
+                        buf_writer.add_value_import(natures, name, &guard::guard_names(name).0)?;
+                    }
+                }
+                buf_writer.push(guards);
+            }
+        }
+    }
+    buf_writer.write_all(output);
     Ok(())
 }
@@ -23,6 +23,8 @@ use std::ops::Deref;
 ///   - `Union` → `type` union of object variants
 ///   - `DiscriminatedUnion` → `type` union with string literals or object forms
 ///
+///   Methods of enum aren't rendered: they exist only in `lib.js` (declared in `*.d.ts`)
+///
 /// - `EnumVariant` → renders fields of a variant according to representation style and field layout
 ///
 /// - `Field` → rendered in parent container (struct, class, etc.) with its resolved type
@@ -31,7 +33,8 @@ use std::ops::Deref;
 ///
 /// - `FuncArg` → generates argument declaration in function definition (used inside `Func`)
 ///
-/// - `Struct` → rendered as `interface` or `abstract class` depending on context flags
+/// - `Struct` → rendered as `interface` or `abstract class` depending on context flags;
+///   getters and setters are rendered as properties (`readonly` if there is no setter)
 ///
 /// - `TupleStruct` → rendered as a `type` alias (e.g., `type MyTuple = [T, U]`)
 ///
@@ -52,7 +55,8 @@ use std::ops::Deref;
 ///
 /// - `EnumVariant` → referenced by variant name as literal or inline object
 ///
-/// - `Field` → renders `name: Type` or method signature (e.g., `public abstract method(): Type`)
+/// - `Field` → renders `name: Type` or method signature (e.g., `public abstract method(): Type`);
+///   static methods are rendered as `public static method: (...) => Type`
 ///
 /// - `Ref` → emits reference to another module or injects import if necessary
 ///
@@ -76,13 +80,9 @@ impl Interpreter for Referred {
         offset: Offset,
         parent: Option<String>,
     ) -> Result<(), E> {
+        let emitter = offset.emitter();
         match self {
-            Referred::Enum(name, _context, variants, repres) => {
-                if let Some(module) = natures.get_module_of(name) {
-                    if natures.exists_in_module(name, &module) {
-                        buf.add_export(name, &module)?;
-                    }
-                }
+            Referred::Enum(name, _context, variants, repres, _) => {
                 let flat = Referred::is_flat_varians(variants)?;
                 if flat {
                     buf.push(format!("{offset}export enum {name} {{\n",));
@@ -102,7 +102,7 @@ impl Interpreter for Referred {
                                     offset.inc(),
                                     Some(name.to_owned()),
                                 )?;
-                                buf.push(";\n");
+                                buf.push(format!("{}\n", emitter.semi()));
                             }
                             buf.push(format!("{offset}}}\n",));
                         }
@@ -117,7 +117,7 @@ impl Interpreter for Referred {
                                 )?;
                                 buf.push(if n == variants.len() - 1 { "" } else { " |\n" });
                             }
-                            buf.push(format!("{offset};\n",));
+                            buf.push(format!("{}\n", emitter.semi()));
                         }
                     }
                 }
@@ -138,7 +138,7 @@ impl Interpreter for Referred {
                                 buf.push(format!("{offset}{{ {name}: null }}"));
                             }
                             EnumRepresentation::DiscriminatedUnion => {
-                                buf.push(format!("{offset}\"{name}\""));
+                                buf.push(format!("{offset}{}", emitter.str(name)));
                             }
                         }
                     }
@@ -230,7 +230,11 @@ impl Interpreter for Referred {
                             parent.clone(),
                         )?;
                         if i < fields.len() - 1 {
-                            buf.push(if named { ";\n" } else { "," });
+                            buf.push(if named {
+                                format!("{}\n", emitter.semi())
+                            } else {
+                                ",".to_owned()
+                            });
                         }
                     }
                     buf.push(if named {
@@ -263,7 +267,7 @@ impl Interpreter for Referred {
                 buf.push(format!("{}: ", context.rename_field(name)?))
             }
             Referred::Func(name, context, func) => {
-                if let Nature::Composite(Composite::Func(_, args, out, asyncness, constructor)) =
+                if let Nature::Composite(Composite::Func(_, args, out, asyncness, constructor, _)) =
                     func.deref()
                 {
                     if *constructor {
@@ -277,7 +281,7 @@ impl Interpreter for Referred {
                         context.rename_method(name)?
                     ));
                     for (i, ty) in args.iter().enumerate() {
-                        ty.declaration(natures, buf, Offset::new(), Some(name.to_owned()))?;
+                        ty.declaration(natures, buf, emitter.offset(), Some(name.to_owned()))?;
                         if i < args.len() - 1 {
                             buf.push(", ");
                         }
@@ -294,7 +298,7 @@ impl Interpreter for Referred {
                     if *asyncness {
                         buf.push(">");
                     }
-                    buf.push(";\n");
+                    buf.push(format!("{}\n", emitter.semi()));
                 } else {
                     return Err(E::Parsing(format!("Cannot find body of function {name}")));
                 }
@@ -312,39 +316,57 @@ impl Interpreter for Referred {
                         "export interface"
                     },
                 ));
-                if let Some(module) = natures.get_module_of(name) {
-                    if natures.exists_in_module(name, &module) {
-                        buf.add_export(name, &module)?;
-                    }
-                }
                 for field in fields {
                     if context.as_class() && field.is_method_constructor() {
                         continue;
                     }
+                    if !context.as_class() && field.is_method_static() {
+                        // Interfaces cannot declare static members
+                        continue;
+                    }
                     if field.is_field_ignored() {
                         continue;
                     }
-                    field.reference(natures, buf, offset.inc(), Some(name.to_owned()))?;
-                    buf.push(";\n");
+                    if let Some((property, out)) = field.get_getter()? {
+                        buf.push(format!(
+                            "{}{}{property}: ",
+                            offset.inc(),
+                            if Nature::has_setter(fields, &property) {
+                                ""
+                            } else {
+                                "readonly "
+                            }
+                        ));
+                        out.reference(natures, buf, offset.inc(), Some(name.to_owned()))?;
+                    } else if let Some((property, arg)) = field.get_setter() {
+                        if Nature::has_getter(fields, &property)? {
+                            // Property is already declared by getter
+                            continue;
+                        }
+                        buf.push(format!("{}{property}: ", offset.inc()));
+                        arg.reference(natures, buf, offset.inc(), Some(name.to_owned()))?;
+                    } else {
+                        field.reference(natures, buf, offset.inc(), Some(name.to_owned()))?;
+                    }
+                    buf.push(format!("{}\n", emitter.semi()));
                 }
                 buf.push(format!("{offset}}}\n",));
             }
             Referred::TupleStruct(name, _context, field) => {
                 buf.push(format!("{offset}export type {name} = ",));
-                if let Some(module) = natures.get_module_of(name) {
-                    if natures.exists_in_module(name, &module) {
-                        buf.add_export(name, &module)?;
-                    }
-                }
                 if let Some(field) = field {
-                    field.reference(natures, buf, Offset::new(), Some(name.to_owned()))?;
+                    field.reference(natures, buf, emitter.offset(), Some(name.to_owned()))?;
                 } else {
                     buf.push("undefined");
                 }
-                buf.push(";\n");
+                buf.push(format!("{}\n", emitter.semi()));
             }
             Referred::Constant(name, _context, _ty, value) => {
-                buf.push(format!("{offset}export const {name} = {value};\n",));
+                buf.push(format!(
+                    "{offset}export const {name} = {}{}\n",
+                    emitter.expression(value),
+                    emitter.semi()
+                ));
             }
             Referred::Ref(ref_name, ..) => {
                 return Err(E::Parsing(format!("Reference {ref_name} can be declared")));
@@ -369,13 +391,21 @@ impl Interpreter for Referred {
             Referred::Enum(name, ..) => buf.push(name),
             Referred::EnumVariant(name, ..) => buf.push(format!("{offset}{name}")),
             Referred::Field(name, context, nature, _) => {
-                if let Nature::Composite(Composite::Func(_, _, _, _, constructor)) = nature.deref()
+                if let Nature::Composite(Composite::Func(_, _, _, _, constructor, static_method)) =
+                    nature.deref()
                 {
                     if *constructor {
                         if context.as_class() {
                             return Ok(());
                         }
                         buf.push(format!("{offset}constructor"));
+                    } else if *static_method {
+                        // Abstract classes cannot have static methods without body,
+                        // so static method is represented as function-typed static property
+                        buf.push(format!(
+                            "{offset}public static {}: ",
+                            context.rename_method(name)?,
+                        ));
                     } else {
                         buf.push(format!(
                             "{offset}{}{}",
@@ -393,7 +423,15 @@ impl Interpreter for Referred {
                         // This is name of unnamed field of TupleStruct
                         buf.push(&(context.rename_field(name)?));
                     } else {
-                        buf.push(format!("{offset}{}: ", context.rename_field(name)?));
+                        buf.push(format!(
+                            "{offset}{}{}: ",
+                            if context.as_readonly() {
+                                "readonly "
+                            } else {
+                                ""
+                            },
+                            context.rename_field(name)?
+                        ));
                         if let Nature::Referred(Referred::Ref(ref_name, _)) = nature.deref() {
                             if let Some(generic) = context.get_generic(ref_name) {
                                 generic.reference(natures, buf, offset, parent)?;
@@ -413,17 +451,7 @@ impl Interpreter for Referred {
             Referred::Struct(name, ..) => buf.push(name),
             Referred::TupleStruct(name, ..) => buf.push(name),
             Referred::Ref(ref_name, ..) => {
-                if let Some(module) = parent.and_then(|p| natures.get_module_of(&p)) {
-                    if let (Some(ref_mod), false) = (
-                        natures.get_module_of(ref_name),
-                        natures.exists_in_module(ref_name, &module),
-                    ) {
-                        buf.add_import(ref_name, ref_mod)?;
-                    }
-                    if natures.exists_in_module(ref_name, &module) {
-                        buf.add_export(ref_name, &module)?;
-                    }
-                }
+                buf.add_import(natures, ref_name)?;
                 buf.push(ref_name)
             }
             Referred::Constant(name, ..) => {
@@ -0,0 +1,149 @@
+use crate::{
+    config,
+    context::Target,
+    error::E,
+    interpreter::{absolute, specifier, ts::Import, Emitter, Output},
+    nature::Natures,
+};
+use std::{collections::BTreeSet, path::PathBuf};
+
+/// Responsible for generating a single `.ts` file, including buffered content
+/// and `import` statements.
+///
+/// Used during the TypeScript code generation phase to assemble declarations,
+/// resolve imports, and render output in a deterministic and append-safe manner.
+/// Rendered content is collected in memory (`Output`) and written to disk afterwards.
+///
+/// # Fields
+/// - `rendered`: Content of the file rendered before this writer (used to avoid duplicated imports).
+/// - `file_name`: Full path to the `.ts` file being written.
+/// - `buffer`: Temporary in-memory buffer for main body (declarations, etc.).
+/// - `imports`: Set of all imports that should be emitted at the top of the file (sorted).
+/// - `emitter`: Renders code in the code style of the configuration.
+pub struct Writer {
+    rendered: String,
+    file_name: PathBuf,
+    buffer: String,
+    imports: BTreeSet<Import>,
+    emitter: Emitter,
+}
+
+impl Writer {
+    /// Creates a new `Writer` for the given file.
+    ///
+    /// # Arguments
+    /// - `file_name`: Full path to the file.
+    /// - `rendered`: Content of the file rendered so far, if any.
+    /// - `emitter`: Renders code in the code style of the configuration.
+    pub fn new(file_name: PathBuf, rendered: Option<&str>, emitter: Emitter) -> Self {
+        Self {
+            rendered: rendered.unwrap_or_default().to_owned(),
+            file_name,
+            buffer: String::new(),
+            imports: BTreeSet::new(),
+            emitter,
+        }
+    }
+
+    /// Returns the emitter of the file.
+    pub fn emitter(&self) -> Emitter {
+        self.emitter
+    }
+
+    /// Finalizes file generation by appending all collected `import` statements
+    /// and the buffered code content to the in-memory content of the file.
+    ///
+    /// Ensures the file begins with imports, followed by the body.
+    pub fn write_all(&self, output: &mut Output) {
+        for import in self.imports.iter() {
+            output.append(
+                &self.file_name,
+                format!("{}\n", import.render(&self.emitter)),
+            );
+        }
+        output.append(&self.file_name, &self.buffer);
+    }
+
+    /// Appends the given string to the internal buffer.
+    ///
+    /// This buffer accumulates TypeScript declarations or code snippets before being flushed.
+    ///
+    /// # Arguments
+    /// - `data`: The string to append.
+    pub fn push<S: AsRef<str>>(&mut self, data: S) {
+        self.buffer.push_str(data.as_ref());
+    }
+
+    /// Registers the `import` of the given entity to be written at the top of the file, if the
+    /// entity is written into another `*.ts` file and the import isn't already present.
+    ///
+    /// The specifier is relative to the folder of the current file (`./module_a`,
+    /// `../models/user`) with the configured `import_extension` (e.g. `.js`).
+    ///
+    /// # Arguments
+    /// - `natures`: All known entities.
+    /// - `name`: The imported entity name.
+    ///
+    /// # Errors
+    /// Returns an error if paths or configuration cannot be accessed.
+    pub fn add_import(&mut self, natures: &Natures, name: &str) -> Result<(), E> {
+        self.import_from(natures, name, name, true)
+    }
+
+    /// Registers the `import` of a value of the given entity (zod schema `UserSchema`, type
+    /// guard `isUser`), if the entity is written into another `*.ts` file. See
+    /// [`Writer::add_import`].
+    ///
+    /// # Errors
+    /// Returns an error if paths or configuration cannot be accessed.
+    pub fn add_value_import(
+        &mut self,
+        natures: &Natures,
+        name: &str,
+        value: &str,
+    ) -> Result<(), E> {
+        self.import_from(natures, name, value, false)
+    }
+
+    /// Registers the `import { z } from "zod";` to be written at the top of the file.
+    pub fn add_zod_import(&mut self) {
+        self.insert(Import {
+            entity: String::from("z"),
+            specifier: String::from("zod"),
+            type_only: false,
+        });
+    }
+
+    fn import_from(
+        &mut self,
+        natures: &Natures,
+        name: &str,
+        entity: &str,
+        type_only: bool,
+    ) -> Result<(), E> {
+        let target = match natures.get_target_of(name, &Target::Ts) {
+            Some(target) => target,
+            None => return Ok(()),
+        };
+        if absolute(&target)? == absolute(&self.file_name)? {
+            return Ok(());
+        }
+        let location = self.file_name.parent().unwrap_or(&self.file_name);
+        self.insert(Import {
+            entity: entity.to_owned(),
+            specifier: format!(
+                "{}{}",
+                specifier(location, &target.with_extension(""))?,
+                config::get()?.import_extension
+            ),
+            type_only,
+        });
+        Ok(())
+    }
+
+    fn insert(&mut self, import: Import) {
+        if !self.rendered.contains(&import.render(&self.emitter)) {
+            self.imports.insert(import);
+        }
+    }
+}
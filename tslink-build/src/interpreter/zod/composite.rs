@@ -0,0 +1,65 @@
+use super::{tuple, Interpreter};
+use crate::{
+    error::E,
+    interpreter::Offset,
+    nature::{Composite, Natures, Primitive},
+};
+
+/// Zod schemas of composite types.
+///
+/// - `Array`, `Vec` → `z.array(...)`
+/// - `HashMap` → `z.record(...)` transformed into `Map` (JSON has only objects; keys of
+///   numeric maps are converted)
+/// - `Tuple` → `z.tuple([...])`
+/// - `Option` → `.nullable()`
+/// - `Undefined` → `z.void()`
+/// - `Result`, `Func` → not supported, only data can be described
+impl Interpreter for Composite {
+    fn zod(&self, natures: &Natures, offset: Offset, native_enums: bool) -> Result<String, E> {
+        Ok(match self {
+            Self::Array(ty) => format!("z.array({})", ty.zod(natures, offset, native_enums)?),
+            Self::Vec(_, ty) => {
+                if let Some(ty) = ty {
+                    format!("z.array({})", ty.zod(natures, offset, native_enums)?)
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type Vec doesn't include reference to type",
+                    )));
+                }
+            }
+            Self::HashMap(_, key, ty) => {
+                if let (Some(key), Some(ty)) = (key, ty) {
+                    let key = match key {
+                        Primitive::Number(..) => "Number(key)",
+                        Primitive::BigInt(..) => "BigInt(key)",
+                        Primitive::String(..) | Primitive::Boolean(..) => "key",
+                    };
+                    format!(
+                        "z.record(z.string(), {}).transform((value) => Object.entries(value).reduce((map, [key, item]) => map.set({key}, item), new Map()))",
+                        ty.zod(natures, offset, native_enums)?
+                    )
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type HashMap doesn't include reference to type or key",
+                    )));
+                }
+            }
+            Self::Tuple(_, tys) => tuple(natures, tys, offset, native_enums)?,
+            Self::Option(_, ty) => {
+                if let Some(ty) = ty {
+                    format!("{}.nullable()", ty.zod(natures, offset, native_enums)?)
+                } else {
+                    return Err(E::Parsing(String::from(
+                        "Type Option doesn't include reference to type",
+                    )));
+                }
+            }
+            Self::Undefined(..) => String::from("z.void()"),
+            Self::Result(..) | Self::Func(..) => {
+                return Err(E::NotSupported(String::from(
+                    "Results and functions cannot be described with zod schema",
+                )))
+            }
+        })
+    }
+}
@@ -0,0 +1,133 @@
+mod composite;
+mod primitive;
+mod refered;
+
+use crate::{
+    error::E,
+    interpreter::{Emitter, Offset},
+    nature::{Composite, Nature, Natures, Referred},
+};
+use std::ops::Deref;
+
+/// Trait for describing a given type (`Nature`) with a [zod](https://zod.dev) schema (zod 3).
+///
+/// Schemas are rendered from the same `Nature` tree as TypeScript declarations, so the type
+/// inferred from a schema is the declared type. Entities are referred lazily
+/// (`z.lazy(() => UserSchema)`), so schemas don't depend on the order of declarations.
+pub trait Interpreter {
+    /// Returns the schema expression of the type.
+    ///
+    /// # Parameters
+    /// - `natures`: The registry of all known types.
+    /// - `offset`: Current indentation (used by multiline objects).
+    /// - `native_enums`: `true` if flat enums are declared as TypeScript `enum` (`z.nativeEnum`);
+    ///   otherwise flat enums are described by names of variants (`z.enum`).
+    fn zod(&self, natures: &Natures, offset: Offset, native_enums: bool) -> Result<String, E>;
+}
+
+impl Interpreter for Nature {
+    /// Delegates rendering to the specific variant: `Primitive`, `Composite`, or `Referred`.
+    fn zod(&self, natures: &Natures, offset: Offset, native_enums: bool) -> Result<String, E> {
+        match self {
+            Self::Primitive(primitive) => primitive.zod(natures, offset, native_enums),
+            Self::Composite(composite) => composite.zod(natures, offset, native_enums),
+            Self::Referred(refered) => refered.zod(natures, offset, native_enums),
+        }
+    }
+}
+
+/// Returns the name of the schema of the given entity (`User` → `UserSchema`).
+pub fn schema_name(name: &str) -> String {
+    format!("{name}Schema")
+}
+
+/// Returns `true` if a schema is rendered for the entity: interfaces (structs, which aren't
+/// classes and don't have methods), type aliases (tuple structs) and enums. Classes and
+/// methods cannot be represented by data, so such entities don't get schemas.
+pub fn has_schema(nature: &Nature) -> bool {
+    match nature {
+        Nature::Referred(Referred::Struct(_, context, fields)) => {
+            !context.as_class()
+                && !fields.iter().any(|field| {
+                    matches!(
+                        field,
+                        Nature::Referred(Referred::Field(_, _, nature, _))
+                            if matches!(nature.deref(), Nature::Composite(Composite::Func(..)))
+                    )
+                })
+        }
+        Nature::Referred(Referred::TupleStruct(..)) | Nature::Referred(Referred::Enum(..)) => true,
+        _ => false,
+    }
+}
+
+/// Renders the schema of the entity as a constant, e.g.
+/// `export const UserSchema: z.ZodType<User, z.ZodTypeDef, unknown> = z.object({...});`
+/// (TypeScript) or `const UserSchema = z.object({...});` (JavaScript). Returns `None` if the
+/// entity doesn't get a schema (see [`has_schema`]).
+///
+/// # Errors
+/// Returns an error if some type of the entity cannot be described.
+pub fn declaration(
+    name: &str,
+    nature: &Nature,
+    natures: &Natures,
+    emitter: &Emitter,
+    typed: bool,
+) -> Result<Option<String>, E> {
+    if !has_schema(nature) {
+        return Ok(None);
+    }
+    let schema = nature.zod(natures, emitter.offset(), typed)?;
+    Ok(Some(if typed {
+        format!(
+            "export const {}: z.ZodType<{name}, z.ZodTypeDef, unknown> = {schema}{}\n",
+            schema_name(name),
+            emitter.semi()
+        )
+    } else {
+        format!("const {} = {schema}{}\n", schema_name(name), emitter.semi())
+    }))
+}
+
+/// Returns the schema of an object with the given named fields (ignored fields are skipped).
+fn object(
+    natures: &Natures,
+    fields: &[Nature],
+    offset: Offset,
+    native_enums: bool,
+) -> Result<String, E> {
+    let mut schema = String::from("z.object({\n");
+    for field in fields.iter() {
+        if let Nature::Referred(Referred::Field(name, context, ..)) = field {
+            if field.is_field_ignored() {
+                continue;
+            }
+            schema.push_str(&format!(
+                "{}{}: {},\n",
+                offset.inc(),
+                context.rename_field(name)?,
+                field.zod(natures, offset.inc(), native_enums)?
+            ));
+        }
+    }
+    schema.push_str(&format!("{offset}}})"));
+    Ok(schema)
+}
+
+/// Returns the schema of a tuple with the given items.
+fn tuple(
+    natures: &Natures,
+    items: &[Nature],
+    offset: Offset,
+    native_enums: bool,
+) -> Result<String, E> {
+    Ok(format!(
+        "z.tuple([{}])",
+        items
+            .iter()
+            .map(|item| item.zod(natures, offset.clone(), native_enums))
+            .collect::<Result<Vec<String>, E>>()?
+            .join(", ")
+    ))
+}
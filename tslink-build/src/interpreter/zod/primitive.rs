@@ -0,0 +1,19 @@
+use super::Interpreter;
+use crate::{
+    error::E,
+    interpreter::Offset,
+    nature::{Natures, Primitive},
+};
+
+/// Zod schemas of primitive types: `z.number()`, `z.bigint()`, `z.string()`, `z.boolean()`.
+impl Interpreter for Primitive {
+    fn zod(&self, _natures: &Natures, _offset: Offset, _native_enums: bool) -> Result<String, E> {
+        Ok(match self {
+            Self::Number(..) => "z.number()",
+            Self::BigInt(..) => "z.bigint()",
+            Self::String(..) => "z.string()",
+            Self::Boolean(..) => "z.boolean()",
+        }
+        .to_owned())
+    }
+}
@@ -0,0 +1,161 @@
+use super::{has_schema, object, schema_name, tuple, Interpreter};
+use crate::{
+    config::cfg::EnumRepresentation,
+    error::E,
+    interpreter::Offset,
+    nature::{Nature, Natures, Referred},
+};
+use std::ops::Deref;
+
+/// Zod schemas of named entities.
+///
+/// - `Struct` → `z.object({...})`
+/// - `TupleStruct` → schema of the inner type (`z.undefined()` for unit structs)
+/// - `Enum` → depends on variants and `EnumRepresentation` (the same as TypeScript declaration):
+///   - flat (no data in variants) → `z.nativeEnum(Enum)` or `z.enum([...])`
+///   - `Flat` → `z.object({...})` with an optional property per variant
+///   - `Union` → `z.union([...])` of objects with a single property named after a variant
+///   - `DiscriminatedUnion` → as `Union`, but variants without data are `z.literal(...)`
+/// - `EnumVariant` → data of the variant: `z.null()`, the single type, a tuple or an object
+/// - `Field` → schema of its type (generic parameters are resolved)
+/// - `Ref` → `z.lazy(() => EntitySchema)` for entities with schemas and `z.any()` for others
+/// - `Func`, `FuncArg`, `Constant`, `Generic` → not supported
+impl Interpreter for Referred {
+    fn zod(&self, natures: &Natures, offset: Offset, native_enums: bool) -> Result<String, E> {
+        Ok(match self {
+            Referred::Struct(_, _, fields) => object(natures, fields, offset, native_enums)?,
+            Referred::TupleStruct(_, _, field) => {
+                if let Some(field) = field {
+                    field.zod(natures, offset, native_enums)?
+                } else {
+                    String::from("z.undefined()")
+                }
+            }
+            Referred::Enum(name, _, variants, repres, _) => {
+                let mut payloads = Vec::new();
+                for variant in variants.iter() {
+                    if let Nature::Referred(Referred::EnumVariant(variant_name, _, fields, ..)) =
+                        variant
+                    {
+                        payloads.push((
+                            variant_name,
+                            fields.is_empty(),
+                            variant.zod(
+                                natures,
+                                match repres {
+                                    EnumRepresentation::Flat => offset.inc(),
+                                    EnumRepresentation::Union
+                                    | EnumRepresentation::DiscriminatedUnion => offset.inc().inc(),
+                                },
+                                native_enums,
+                            )?,
+                        ));
+                    }
+                }
+                if Referred::is_flat_varians(variants)? {
+                    if native_enums {
+                        format!("z.nativeEnum({name})")
+                    } else {
+                        format!(
+                            "z.enum([{}])",
+                            payloads
+                                .iter()
+                                .map(|(name, ..)| offset.emitter().str(name))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        )
+                    }
+                } else {
+                    match repres {
+                        EnumRepresentation::Flat => format!(
+                            "z.object({{\n{}{offset}}})",
+                            payloads
+                                .iter()
+                                .map(|(name, _, payload)| format!(
+                                    "{}{name}: {payload}.optional(),\n",
+                                    offset.inc()
+                                ))
+                                .collect::<String>()
+                        ),
+                        EnumRepresentation::Union | EnumRepresentation::DiscriminatedUnion => {
+                            let variants = payloads
+                                .iter()
+                                .map(|(name, unit, payload)| {
+                                    if *unit
+                                        && matches!(repres, EnumRepresentation::DiscriminatedUnion)
+                                    {
+                                        format!("z.literal({})", offset.emitter().str(name))
+                                    } else {
+                                        format!(
+                                            "z.object({{\n{}{name}: {payload},\n{}}})",
+                                            offset.inc().inc(),
+                                            offset.inc()
+                                        )
+                                    }
+                                })
+                                .collect::<Vec<String>>();
+                            if variants.len() == 1 {
+                                // z.union() requires at least two options
+                                variants.join("")
+                            } else {
+                                format!(
+                                    "z.union([\n{}{offset}])",
+                                    variants
+                                        .iter()
+                                        .map(|variant| format!("{}{variant},\n", offset.inc()))
+                                        .collect::<String>()
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+            Referred::EnumVariant(_, _, fields, ..) => {
+                if fields.is_empty() {
+                    String::from("z.null()")
+                } else if fields
+                    .iter()
+                    .any(|f| matches!(f, Nature::Referred(Referred::Field(..))))
+                {
+                    object(natures, fields, offset, native_enums)?
+                } else if fields.len() == 1 {
+                    fields
+                        .first()
+                        .ok_or(E::Parsing(String::from(
+                            "Expecting single field for Variant",
+                        )))?
+                        .zod(natures, offset, native_enums)?
+                } else {
+                    tuple(natures, fields, offset, native_enums)?
+                }
+            }
+            Referred::Field(_, context, nature, _) => {
+                if let Nature::Referred(Referred::Ref(ref_name, _)) = nature.deref() {
+                    if let Some(generic) = context.get_generic(ref_name) {
+                        return generic.zod(natures, offset, native_enums);
+                    }
+                }
+                nature.zod(natures, offset, native_enums)?
+            }
+            Referred::Ref(ref_name, ..) => {
+                if natures.get_entity(ref_name).is_some_and(has_schema) {
+                    format!("z.lazy(() => {})", schema_name(ref_name))
+                } else {
+                    String::from("z.any()")
+                }
+            }
+            Referred::Func(name, ..)
+            | Referred::FuncArg(name, ..)
+            | Referred::Constant(name, ..) => {
+                return Err(E::NotSupported(format!(
+                    "{name} cannot be described with zod schema"
+                )));
+            }
+            Referred::Generic(alias, ..) => {
+                return Err(E::Parsing(format!(
+                    "Generic type cannot be rendered out of context; type alias = {alias}"
+                )))
+            }
+        })
+    }
+}
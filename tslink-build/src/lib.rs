@@ -0,0 +1,50 @@
+//! Library API of [tslink](https://crates.io/crates/tslink).
+//!
+//! Generates TypeScript definitions (`*.d.ts`, `*.ts`) and JavaScript bindings (`*.js`) for a crate
+//! without relying on the side effects of the `#[tslink]` macro expansion. The crate's modules are
+//! walked with `syn`, items annotated with `#[tslink]` are read and all artifacts are written once.
+//!
+//! Usually it's used in `build.rs`:
+//!
+//! ```ignore
+//! fn main() {
+//!     if let Err(err) = tslink_build::Generator::new().crate_root("src/lib.rs").run() {
+//!         panic!("{err}");
+//!     }
+//! }
+//! ```
+//!
+//! Configuration is taken from `[package.metadata.tslink]` of `Cargo.toml` (the same as for the macro).
+//! Unlike the macro, the generator doesn't need the environment variable `TSLINK_BUILD`.
+
+mod config;
+mod context;
+mod error;
+mod generator;
+mod interpreter;
+#[doc(hidden)]
+pub mod macros;
+mod modificator;
+mod nature;
+mod package;
+mod reader;
+mod registry;
+
+#[macro_use]
+extern crate lazy_static;
+
+use config::Config;
+use interpreter::Journal;
+use nature::Natures;
+use std::sync::RwLock;
+
+pub use generator::{Error, Generator};
+
+lazy_static! {
+    #[doc(hidden)]
+    static ref CONFIG: RwLock<Config> = RwLock::new(Config::default());
+    #[doc(hidden)]
+    static ref NATURES: RwLock<Natures> = RwLock::new(Natures::new());
+    #[doc(hidden)]
+    static ref JOURNAL: RwLock<Journal> = RwLock::new(Journal::default());
+}
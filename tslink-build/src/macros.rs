@@ -0,0 +1,60 @@
+//! Implementation of `#[tslink]` and `tslink::finalize!()`; used by the proc-macro crate `tslink`.
+
+use crate::{config, context::Context, interpreter, reader, registry, CONFIG, NATURES};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::{convert::TryInto, path::PathBuf};
+use syn::Item;
+
+/// Expands `#[tslink]`: reads the given item and returns it (modified if needed) with generated
+/// functions (exported methods of enums).
+///
+/// `source` is the file of the item; it's used by the persistent registry of entities.
+pub fn tslink(args: TokenStream, item: TokenStream, source: Option<PathBuf>) -> TokenStream {
+    if let Err(err) = config::setup() {
+        return syn::Error::new_spanned(item.to_string(), err.to_string()).into_compile_error();
+    }
+    let item_ref = item.clone();
+    let context: Context = match syn::parse2(args) {
+        Ok(context) => context,
+        Err(err) => return err.into_compile_error(),
+    };
+    if context.ignore_self() {
+        item
+    } else {
+        let mut natures = NATURES.write().expect("Get access to list of entities");
+        let mut item: Item = match syn::parse2(item) {
+            Ok(item) => item,
+            Err(err) => return err.into_compile_error(),
+        };
+        let cfg = CONFIG.read().expect("Read configuration");
+        natures.set_source(source);
+        match reader::read(&mut item, &mut natures, context, &cfg) {
+            Ok(exported) => quote! { #item #(#exported)* },
+            Err(err) => {
+                let str_err = err.to_string();
+                TryInto::<syn::Error>::try_into(err)
+                    .unwrap_or(syn::Error::new_spanned(item_ref.to_string(), str_err))
+                    .into_compile_error()
+            }
+        }
+    }
+}
+
+/// Expands `tslink::finalize!()`: generates all artifacts for entities collected so far. Without
+/// `deferred_generation` artifacts are generated by each `#[tslink]`, so nothing is done.
+pub fn finalize() -> TokenStream {
+    if let Err(err) = config::setup() {
+        return syn::Error::new(Span::call_site(), err.to_string()).into_compile_error();
+    }
+    let natures = NATURES.read().expect("Get access to list of entities");
+    let cfg = CONFIG.read().expect("Read configuration");
+    if cfg.io_allowed && cfg.deferred_generation {
+        if let Err(err) =
+            registry::sync(&natures, true).and_then(|natures| interpreter::generate(&natures, &cfg))
+        {
+            return syn::Error::new(Span::call_site(), err.to_string()).into_compile_error();
+        }
+    }
+    TokenStream::new()
+}
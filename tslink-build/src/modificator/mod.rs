@@ -0,0 +1,413 @@
+use std::ops::Deref;
+
+use crate::{
+    config::{self, cfg::Backend},
+    context::Context,
+    error::E,
+    nature::{Composite, Extract, Nature, Referred, TypeTokenStream, VariableTokenStream},
+};
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{parse_quote, FnArg, Pat, ReturnType, Type};
+use syn::{Block, ImplItemFn, ItemFn};
+
+pub enum FnItem<'a> {
+    ItemFn(&'a mut ItemFn),
+    ImplItemFn(&'a mut ImplItemFn),
+}
+
+impl FnItem<'_> {
+    pub fn get_block(&self) -> &Block {
+        match self {
+            Self::ImplItemFn(item) => &item.block,
+            Self::ItemFn(item) => &item.block,
+        }
+    }
+
+    pub fn set_block(&mut self, block: Block) {
+        match self {
+            Self::ImplItemFn(item) => {
+                item.block = block;
+            }
+            Self::ItemFn(item) => {
+                *item.block = block;
+            }
+        }
+    }
+
+    pub fn is_async(&self) -> bool {
+        match self {
+            Self::ImplItemFn(item) => item.sig.asyncness.is_some(),
+            Self::ItemFn(item) => item.sig.asyncness.is_some(),
+        }
+    }
+
+    pub fn set_output(&mut self, output: ReturnType) {
+        match self {
+            Self::ImplItemFn(item) => {
+                item.sig.output = output;
+            }
+            Self::ItemFn(item) => {
+                item.sig.output = output;
+            }
+        }
+    }
+}
+
+fn split_fn_out(out: &Option<Box<Nature>>) -> (Option<Nature>, Option<Nature>) {
+    if let Some(out) = out {
+        if let Nature::Composite(Composite::Result(_, res, err, _, _)) = out.deref() {
+            (
+                res.clone().map(|n| n.deref().clone()),
+                err.clone().map(|n| n.deref().clone()),
+            )
+        } else {
+            (Some(out.deref().clone()), None)
+        }
+    } else {
+        (None, None)
+    }
+}
+
+/// Returns the output type of the modified function. napi-rs functions return `napi::Result`,
+/// so errors are converted into `napi::Error` (see [`native_err`]).
+fn native_output(res: TokenStream, err: TokenStream, backend: &Backend) -> TokenStream {
+    match backend {
+        Backend::NodeBindgen | Backend::WasmBindgen => quote! { -> Result<#res, #err> },
+        Backend::NapiRs => quote! { -> napi::Result<#res> },
+    }
+}
+
+/// Returns the error returned by the modified function. With napi-rs errors are converted into
+/// `napi::Error`: errors serialized to JSON become the reason of the error, other errors should
+/// implement `Into<napi::Error>`.
+fn native_err(err: TokenStream, as_json: bool, backend: &Backend) -> TokenStream {
+    match backend {
+        Backend::NodeBindgen | Backend::WasmBindgen => err,
+        Backend::NapiRs if as_json => quote! { napi::Error::from_reason(#err) },
+        Backend::NapiRs => quote! { Into::<napi::Error>::into(#err) },
+    }
+}
+
+/// Returns the expression, which evaluates the origin body of the function. The body is wrapped
+/// into a closure (or an async block for async functions), so `?` and `return` refer to the
+/// origin `Result` instead of the modified output.
+fn origin_body(item: &FnItem) -> TokenStream {
+    let stmts = &item.get_block().stmts;
+    if item.is_async() {
+        quote! {
+            async move {
+                #(#stmts)*
+            }.await
+        }
+    } else {
+        quote! {
+            (move || {
+                #(#stmts)*
+            })()
+        }
+    }
+}
+
+fn bind(item: &mut FnItem, name: &str, context: &Context, fn_nature: &Nature) -> Result<(), E> {
+    let (args, out) = if let Nature::Composite(Composite::Func(_, args, out, ..)) = fn_nature {
+        (args, out)
+    } else {
+        return Err(E::Parsing(format!("Fail to parse fn/method \"{name}\"")));
+    };
+    let bindings = context.get_bound_args();
+    let mut unknown: Vec<String> = vec![];
+    bindings.iter().for_each(|(name, _)| {
+        if !args.iter().any(|nature| {
+            if let Nature::Referred(Referred::FuncArg(n, _, _, _)) = nature {
+                name == n
+            } else {
+                false
+            }
+        }) && name != "result"
+        {
+            unknown.push(name.to_owned());
+        }
+    });
+    if !unknown.is_empty() {
+        return Err(E::Parsing(format!(
+            "Unknown arguments to bind: {}",
+            unknown.join(", ")
+        )));
+    }
+    let (fn_res, fn_err) = split_fn_out(out);
+    let fn_err_type_ref = if let Some(fn_err) = fn_err.as_ref() {
+        Some(fn_err.type_token_stream()?)
+    } else {
+        None
+    };
+    let bindings = bindings
+        .iter()
+        .map(|(name, ref_name)| {
+            let varname = format_ident!("{}", name);
+            let refname = format_ident!("{}", ref_name);
+            if let Some(fn_err_type_ref) = fn_err_type_ref.as_ref() {
+                quote! {
+                    #[allow(unused_mut)]
+                    let mut #varname: #refname = serde_json::from_str(&#varname).map_err(|e| Into::<#fn_err_type_ref>::into(e))?;
+                }
+            } else {
+                quote! {
+                    #[allow(unused_mut)]
+                    let mut #varname: #refname = serde_json::from_str(&#varname).expect("Parsing from JSON string")?;
+                }
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+    if !bindings.is_empty() {
+        let stmts = &item.get_block().stmts;
+        let block = quote! {
+            use serde_json;
+            #(#bindings)*;
+            #(#stmts)*
+        };
+        item.set_block(parse_quote! {{#block}});
+    }
+    let result_as_json = context.result_as_json()?;
+    let error_as_json = context.error_as_json()?;
+    if result_as_json || error_as_json {
+        let (fn_res, fn_err) = (
+            fn_res.ok_or(E::Parsing("Fail to get Ok option of Result. If result defined as JSON, function/method should return Result<T,E>".to_string()))?,
+            fn_err.ok_or(E::Parsing("Fail to get Err option of Result. If result defined as JSON, function/method should return Result<T,E>".to_string()))?,
+        );
+        let res_rust_type = fn_res.type_token_stream()?;
+        let err_rust_type = fn_err.type_token_stream()?;
+        let backend = config::get()?.backend;
+        if result_as_json && error_as_json {
+            let res_token = fn_res.variable_token_stream("res", None)?;
+            let err_token = native_err(fn_err.variable_token_stream("err", None)?, true, &backend);
+            let body = origin_body(item);
+            let block = quote! {
+                let result: Result<#res_rust_type, #err_rust_type> = #body;
+                match result {
+                    Ok(res) => Ok(#res_token),
+                    Err(err) => Err(#err_token)
+                }
+            };
+            item.set_block(parse_quote! {{#block}});
+            let output = native_output(quote! { String }, quote! { String }, &backend);
+            item.set_output(parse_quote! {#output});
+        } else if result_as_json {
+            let res_token = fn_res.variable_token_stream("res", Some(&fn_err))?;
+            let err_token = {
+                let err = format_ident!("{}", "err");
+                native_err(quote! {#err}, false, &backend)
+            };
+            let body = origin_body(item);
+            let block = quote! {
+                let result: Result<#res_rust_type, #err_rust_type> = #body;
+                match result {
+                    Ok(res) => Ok(#res_token),
+                    Err(err) => Err(#err_token)
+                }
+            };
+            item.set_block(parse_quote! {{#block}});
+            let output = native_output(quote! { String }, quote! { #err_rust_type }, &backend);
+            item.set_output(parse_quote! {#output});
+        } else if error_as_json {
+            let res_token = {
+                let res = format_ident!("{}", "res");
+                quote! {#res}
+            };
+            let err_token = native_err(fn_err.variable_token_stream("err", None)?, true, &backend);
+            let body = origin_body(item);
+            let block = quote! {
+                let result: Result<#res_rust_type, #err_rust_type> = #body;
+                match result {
+                    Ok(res) => Ok(#res_token),
+                    Err(err) => Err(#err_token)
+                }
+            };
+            item.set_block(parse_quote! {{#block}});
+            let output = native_output(res_rust_type, quote! { String }, &backend);
+            item.set_output(parse_quote! {#output});
+        }
+    }
+    Ok(())
+}
+
+pub fn bind_fn(
+    item: &mut ItemFn,
+    name: &str,
+    context: &Context,
+    fn_nature: &Nature,
+) -> Result<(), E> {
+    bind(&mut FnItem::ItemFn(item), name, context, fn_nature)
+}
+
+pub fn bind_impl_fn(
+    item: &mut ImplItemFn,
+    name: &str,
+    context: &Context,
+    fn_nature: &Nature,
+) -> Result<(), E> {
+    bind(&mut FnItem::ImplItemFn(item), name, context, fn_nature)
+}
+
+/// Returns the attribute, which exports a function from the native module with the given name.
+fn native_export(js_name: &str, backend: &Backend) -> TokenStream {
+    match backend {
+        Backend::NodeBindgen => quote! { #[node_bindgen::derive::node_bindgen(name = #js_name)] },
+        Backend::NapiRs => quote! { #[napi_derive::napi(js_name = #js_name)] },
+        Backend::WasmBindgen => {
+            quote! { #[wasm_bindgen::prelude::wasm_bindgen(js_name = #js_name)] }
+        }
+    }
+}
+
+/// Replaces `Self` with the given type (including nested tokens).
+fn replace_self(tokens: TokenStream, ty: &Ident) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) if ident == "Self" => TokenTree::Ident(ty.clone()),
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    proc_macro2::Group::new(group.delimiter(), replace_self(group.stream(), ty));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+/// Returns `true` if tokens refer to the given type.
+fn refers_to(tokens: TokenStream, ty: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => &ident == ty,
+        TokenTree::Group(group) => refers_to(group.stream(), ty),
+        _ => false,
+    })
+}
+
+/// Returns the function, which exports a method of an enum from the native module.
+///
+/// Enum values cannot be passed to the native module as they are, so the enum value (`self`)
+/// and arguments of the enum's type are passed as JSON strings; an output, which refers to the
+/// enum, is returned as a JSON string (the enum should implement `Serialize` and
+/// `Deserialize`). Bindings of the method (`data`, `result`, `error`) are applied to the
+/// exported function instead of the method, so the method itself stays unchanged.
+///
+/// # Parameters
+/// - `fn_item`: The method of the enum.
+/// - `enum_name`: Name of the enum.
+/// - `name`: Name of the exported function in Rust (`level_label`).
+/// - `js_name`: Name of the exported function in the native module (`levelLabel`).
+/// - `context`: Context of the method.
+/// - `cfg`: Global configuration settings.
+///
+/// # Errors
+/// Returns an error if the enum is used in arguments not as `self`, `Self` or the enum itself
+/// (`Vec<Self>`), or if bindings of the method cannot be applied.
+pub fn enum_method(
+    fn_item: &ImplItemFn,
+    enum_name: &str,
+    name: &str,
+    js_name: &str,
+    context: &Context,
+    cfg: &config::Config,
+) -> Result<ItemFn, E> {
+    let enum_ident = format_ident!("{}", enum_name);
+    let method = &fn_item.sig.ident;
+    let mut inputs: Vec<TokenStream> = vec![];
+    let mut parsing: Vec<TokenStream> = vec![];
+    let mut args: Vec<TokenStream> = vec![];
+    let mut receiver = false;
+    for input in fn_item.sig.inputs.iter() {
+        match input {
+            FnArg::Receiver(_) => {
+                receiver = true;
+                inputs.push(quote! { value: String });
+                parsing.push(quote! {
+                    #[allow(unused_mut)]
+                    let mut value: #enum_ident = serde_json::from_str(&value).expect("Parsing from JSON string");
+                });
+            }
+            FnArg::Typed(typed) => {
+                let arg = if let Pat::Ident(pat) = typed.pat.as_ref() {
+                    pat.ident.clone()
+                } else {
+                    return Err(E::Parsing(String::from("Cannot find ident for FnArg")));
+                };
+                let (ty, reference) = match typed.ty.as_ref() {
+                    Type::Reference(ty_ref) => (ty_ref.elem.as_ref(), Some(ty_ref.mutability)),
+                    ty => (ty, None),
+                };
+                let own = matches!(ty, Type::Path(path) if path.qself.is_none()
+                    && (path.path.is_ident("Self") || path.path.is_ident(&enum_ident)));
+                if own {
+                    inputs.push(quote! { #arg: String });
+                    parsing.push(quote! {
+                        #[allow(unused_mut)]
+                        let mut #arg: #enum_ident = serde_json::from_str(&#arg).expect("Parsing from JSON string");
+                    });
+                    args.push(match reference {
+                        Some(Some(_)) => quote! { &mut #arg },
+                        Some(None) => quote! { &#arg },
+                        None => quote! { #arg },
+                    });
+                } else if refers_to(quote! { #ty }, &format_ident!("Self"))
+                    || refers_to(quote! { #ty }, &enum_ident)
+                {
+                    return Err(E::Parsing(format!(
+                        "Argument \"{arg}\" of method \"{method}\" refers to \"{enum_name}\"; values of enum can be passed only as self, Self or {enum_name}"
+                    )));
+                } else {
+                    inputs.push(quote! { #typed });
+                    args.push(quote! { #arg });
+                }
+            }
+        }
+    }
+    let awaiting = fn_item.sig.asyncness.map(|_| quote! { .await });
+    let call = if receiver {
+        quote! { value.#method(#(#args),*)#awaiting }
+    } else {
+        quote! { #enum_ident::#method(#(#args),*)#awaiting }
+    };
+    let (output, call) = match &fn_item.sig.output {
+        ReturnType::Type(_, ty)
+            if !context.result_as_json()?
+                && (refers_to(quote! { #ty }, &format_ident!("Self"))
+                    || refers_to(quote! { #ty }, &enum_ident)) =>
+        {
+            let ty = replace_self(quote! { #ty }, &enum_ident);
+            if let Nature::Composite(Composite::Result(_, _, Some(err), ..)) = Nature::extract(
+                syn::parse2::<Type>(ty).map_err(|e| E::Parsing(e.to_string()))?,
+                context.clone(),
+                cfg,
+            )? {
+                let err = err.type_token_stream()?;
+                (
+                    quote! { -> Result<String, #err> },
+                    quote! { #call.map(|res| serde_json::to_string(&res).expect("Converting to JSON string")) },
+                )
+            } else {
+                (
+                    quote! { -> String },
+                    quote! { serde_json::to_string(&#call).expect("Converting to JSON string") },
+                )
+            }
+        }
+        output => (replace_self(quote! { #output }, &enum_ident), call),
+    };
+    let asyncness = &fn_item.sig.asyncness;
+    let ident = format_ident!("{}", name);
+    let export = native_export(js_name, &config::get()?.backend);
+    let mut item: ItemFn = parse_quote! {
+        #export
+        pub #asyncness fn #ident(#(#inputs),*) #output {
+            #(#parsing)*
+            #call
+        }
+    };
+    let fn_nature = Nature::extract(&item, context.clone(), cfg)?;
+    bind_fn(&mut item, name, context, &fn_nature)?;
+    Ok(item)
+}
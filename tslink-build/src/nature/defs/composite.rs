@@ -4,6 +4,7 @@ use crate::{
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use serde::{Deserialize, Serialize};
 
 /// Represents structured, non-primitive Rust types that require composition or generic typing.
 ///
@@ -13,7 +14,7 @@ use quote::{format_ident, quote};
 ///
 /// Each variant holds enough information to reconstruct both the Rust and TypeScript representation of the type,
 /// including its subtypes and textual origin (`OriginType`).
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Composite {
     /// Represents array types like `[T; N]`.
     ///
@@ -72,7 +73,15 @@ pub enum Composite {
     /// - `Option<Box<Nature>>` is the return type.
     /// - `bool` indicates whether the function is asynchronous.
     /// - `bool` indicates whether the function is a constructor.
-    Func(OriginType, Vec<Nature>, Option<Box<Nature>>, bool, bool),
+    /// - `bool` indicates whether the function is a static method (associated function without `self`).
+    Func(
+        OriginType,
+        Vec<Nature>,
+        Option<Box<Nature>>,
+        bool,
+        bool,
+        bool,
+    ),
 }
 
 impl TypeTokenStream for Composite {
@@ -4,6 +4,7 @@ use crate::{
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use serde::{Deserialize, Serialize};
 
 /// Represents a primitive type as it will be exposed in the generated TypeScript code.
 ///
@@ -19,7 +20,7 @@ use quote::{format_ident, quote};
 ///
 /// The associated `OriginType` allows the generator to keep track of the exact source type
 /// and apply more specific rules or type checks if needed during code generation.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Primitive {
     Number(OriginType),
     BigInt(OriginType),
@@ -3,6 +3,7 @@ use crate::{
 };
 use proc_macro2::TokenStream;
 use quote::{quote, format_ident};
+use serde::{Deserialize, Serialize};
 
 /// Represents named or referenced entities in a Rust codebase, typically used during TypeScript binding generation.
 ///
@@ -13,7 +14,7 @@ use quote::{quote, format_ident};
 /// to a different language model (e.g., TypeScript or JSON).
 ///
 /// Each variant represents a distinct kind of referable entity with appropriate metadata for code generation.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Referred {
     /// A tuple struct declaration.
     ///
@@ -35,7 +36,14 @@ pub enum Referred {
     /// - `Context`: Where it is defined.
     /// - `Vec<Nature>`: Enum variants.
     /// - `EnumRepresentation`: How the enum is represented (tagged, untagged, externally tagged, etc.).
-    Enum(String, Context, Vec<Nature>, EnumRepresentation),
+    /// - `Vec<Nature>`: Methods from `impl` blocks of the enum (as `Field` with function type).
+    Enum(
+        String,
+        Context,
+        Vec<Nature>,
+        EnumRepresentation,
+        Vec<Nature>,
+    ),
 
     /// A single enum variant, potentially flat (e.g., inline in a struct).
     ///
@@ -105,7 +113,7 @@ impl Referred {
     }
 
     pub fn is_enum_flat(&self) -> Result<bool, E> {
-        if let Referred::Enum(_, _, variants,_) = self {
+        if let Referred::Enum(_, _, variants, ..) = self {
             Referred::is_flat_varians(variants)
         } else {
             Err(E::Parsing(String::from("Given Nature isn't enum")))
@@ -82,6 +82,7 @@ impl ExtractGeneric<&TraitBound> for Nature {
                         output,
                         false,
                         false,
+                        false,
                     ))),
                 ))));
             }
@@ -12,17 +12,34 @@ pub use generic::ExtractGenerics;
 pub use origin::OriginType;
 pub use types::Extract;
 
-use crate::{context::Context, error::E};
-use std::{collections::HashMap, ops::Deref};
+use crate::{
+    context::{Context, Target},
+    error::E,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet, ops::Deref, path::PathBuf};
 
 pub struct NatureDef {
     pub nature: Nature,
     pub module: Option<String>,
+    /// Source file the entity has been read from (if known).
+    pub source: Option<PathBuf>,
+    /// `false` if the entity has been created by an `impl` block and its declaration
+    /// (fields, variants) hasn't been read.
+    pub declared: bool,
+    /// Methods read from `impl` blocks with source files of the blocks (if known).
+    pub methods: Vec<(String, Option<PathBuf>)>,
 }
 
 impl NatureDef {
-    pub fn new(nature: Nature, module: Option<String>) -> Self {
-        Self { nature, module }
+    pub fn new(nature: Nature, module: Option<String>, source: Option<PathBuf>) -> Self {
+        Self {
+            nature,
+            module,
+            source,
+            declared: true,
+            methods: Vec::new(),
+        }
     }
     pub fn get_mut(&mut self) -> &mut Nature {
         &mut self.nature
@@ -34,11 +51,32 @@ impl NatureDef {
         self.nature.clone()
     }
 }
-pub struct Natures(HashMap<String, NatureDef>);
+/// Registry of all read entities.
+///
+/// Entities are kept in order of declaration (reading), so generated output
+/// doesn't depend on hashing and stays the same between builds.
+pub struct Natures {
+    entities: Vec<(String, NatureDef)>,
+    /// Source file of entities being read at the moment.
+    source: Option<PathBuf>,
+}
 
 impl Natures {
     pub fn new() -> Self {
-        Natures(HashMap::new())
+        Natures {
+            entities: Vec::new(),
+            source: None,
+        }
+    }
+    fn find(&self, name: &str) -> Option<&NatureDef> {
+        self.entities
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, def)| def)
+    }
+    /// Sets the source file of entities, which will be inserted next.
+    pub fn set_source(&mut self, source: Option<PathBuf>) {
+        self.source = source;
     }
     pub fn is_any_bound(natures: &[Nature]) -> bool {
         for nature in natures.iter() {
@@ -62,26 +100,38 @@ impl Natures {
             .collect::<Vec<String>>()
     }
     pub fn contains(&self, name: &str) -> bool {
-        self.0.contains_key(name)
+        self.find(name).is_some()
     }
     pub fn insert(&mut self, name: &str, nature: Nature, module: Option<String>) -> Result<(), E> {
         if self.contains(name) {
             Err(E::EntityExist(name.to_owned()))
         } else {
-            let _ = self
-                .0
-                .insert(name.to_owned(), NatureDef::new(nature, module));
+            self.entities.push((
+                name.to_owned(),
+                NatureDef::new(nature, module, self.source.clone()),
+            ));
             Ok(())
         }
     }
+    /// Returns the entity with the given name.
+    pub fn get_entity(&self, name: &str) -> Option<&Nature> {
+        self.find(name).map(|n| n.get())
+    }
     pub fn get_module_of(&self, name: &str) -> Option<String> {
-        self.0.get(name).and_then(|n| n.module.clone())
+        self.find(name).and_then(|n| n.module.clone())
     }
-    pub fn exists_in_module(&self, name: &str, module: &str) -> bool {
-        self.0
-            .get(name)
-            .map(|n| n.module.as_ref().map(|m| m == module).unwrap_or_default())
-            .unwrap_or_default()
+    /// Returns the file of the given kind of the entity (`#[tslink(target = "./file.ts")]`),
+    /// if it's defined.
+    pub fn get_target_of(&self, name: &str, target: &Target) -> Option<PathBuf> {
+        self.find(name)
+            .and_then(|n| n.get().get_context().ok())
+            .and_then(|context| {
+                context
+                    .targets
+                    .iter()
+                    .find(|(t, _)| t == target)
+                    .map(|(_, path)| path.to_owned())
+            })
     }
     pub fn get_mut(
         &mut self,
@@ -89,20 +139,33 @@ impl Natures {
         default_nature: Option<Nature>,
         default_module: Option<String>,
     ) -> Option<&mut Nature> {
-        if let (exists, Some(default_nature)) = (self.0.contains_key(name), default_nature) {
+        if let (exists, Some(default_nature)) = (self.contains(name), default_nature) {
             if !exists {
-                let _ = self.0.insert(
-                    name.to_owned(),
-                    NatureDef::new(default_nature, default_module),
-                );
+                let mut def = NatureDef::new(default_nature, default_module, self.source.clone());
+                def.declared = false;
+                self.entities.push((name.to_owned(), def));
             }
         }
-        self.0.get_mut(name).map(|n| n.get_mut())
+        self.entities
+            .iter_mut()
+            .find(|(n, _)| n == name)
+            .map(|(_, def)| def.get_mut())
+    }
+
+    /// Records methods of the entity read from an `impl` block of the current source file.
+    pub fn add_methods(&mut self, name: &str, methods: Vec<String>) {
+        if let Some((_, def)) = self.entities.iter_mut().find(|(n, _)| n == name) {
+            def.methods.extend(
+                methods
+                    .into_iter()
+                    .map(|method| (method, self.source.clone())),
+            );
+        }
     }
 
     pub fn filter(&self, filter: fn(&Nature) -> bool) -> Vec<Nature> {
         let mut natures: Vec<Nature> = vec![];
-        for (_, n) in self.0.iter() {
+        for (_, n) in self.entities.iter() {
             if filter(n.get()) {
                 natures.push(n.extract());
             }
@@ -111,7 +174,11 @@ impl Natures {
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (&String, &Nature)> {
-        self.0.iter().map(|(k, n)| (k, n.get()))
+        self.entities.iter().map(|(k, n)| (k, n.get()))
+    }
+
+    pub fn defs(&self) -> impl Iterator<Item = (&String, &NatureDef)> {
+        self.entities.iter().map(|(k, n)| (k, n))
     }
 }
 
@@ -133,7 +200,7 @@ impl Natures {
 /// - A primitive `bool`: `Nature::Primitive(Primitive::Boolean(...))`
 /// - A named struct: `Nature::Referred(Referred::Struct(...))`
 /// - A `Vec<String>`: `Nature::Composite(Composite::Vec(...))`
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Nature {
     Primitive(Primitive),
     Referred(Referred),
@@ -146,7 +213,7 @@ impl Nature {
     /// # Errors
     /// Returns an error if `self` is not a `Composite::Func`.
     pub fn get_fn_args_names(&self) -> Result<Vec<String>, E> {
-        if let Nature::Composite(Composite::Func(_, args, _, _, _)) = self {
+        if let Nature::Composite(Composite::Func(_, args, ..)) = self {
             Ok(Natures::get_fn_args_names(args))
         } else {
             Err(E::Parsing("Fail to find arguments of function".to_string()))
@@ -158,7 +225,7 @@ impl Nature {
     /// # Errors
     /// Returns an error if `self` is not a `Composite::Func`.
     pub fn is_fn_async(&self) -> Result<bool, E> {
-        if let Nature::Composite(Composite::Func(_, _, _, asyncness, _)) = self {
+        if let Nature::Composite(Composite::Func(_, _, _, asyncness, ..)) = self {
             Ok(*asyncness)
         } else {
             Err(E::Parsing("Fail to find function".to_string()))
@@ -168,7 +235,8 @@ impl Nature {
     /// Attempts to bind another `Nature` into this one, mutating the internal structure.
     ///
     /// The binding behavior depends on the variant:
-    /// - For `Referred::Struct` / `Enum` / `EnumVariant`, the new `Nature` is added as a field/variant.
+    /// - For `Referred::Struct` / `Enum` / `EnumVariant`, the new `Nature` is added as a field/variant
+    ///   (a `Field` bound to `Enum` is added as a method).
     /// - For `TupleStruct`, the field is set.
     /// - For `Composite` types like `Vec`, `Option`, `Result`, `HashMap`, `Tuple`, the new type is assigned
     ///   to the correct slot (element, key, value, etc.), or an error is returned if already bound.
@@ -188,8 +256,12 @@ impl Nature {
                     let _ = field.insert(Box::new(nature));
                     Ok(())
                 }
-                Referred::Enum(_, _, natures, ..) => {
-                    natures.push(nature);
+                Referred::Enum(_, _, variants, _, methods) => {
+                    if matches!(nature, Nature::Referred(Referred::Field(..))) {
+                        methods.push(nature);
+                    } else {
+                        variants.push(nature);
+                    }
                     Ok(())
                 }
                 Referred::EnumVariant(_, _, natures, ..) => {
@@ -266,13 +338,88 @@ impl Nature {
     /// Used to detect constructor methods in struct definitions.
     pub fn is_method_constructor(&self) -> bool {
         if let Nature::Referred(Referred::Field(_, _, nature, _)) = self {
-            if let Nature::Composite(Composite::Func(_, _, _, _, constructor)) = nature.deref() {
+            if let Nature::Composite(Composite::Func(_, _, _, _, constructor, _)) = nature.deref() {
                 return *constructor;
             }
         }
         false
     }
 
+    /// Returns `true` if this `Nature` represents a field whose type is a static method
+    /// (associated function without `self` receiver).
+    pub fn is_method_static(&self) -> bool {
+        if let Nature::Referred(Referred::Field(_, _, nature, _)) = self {
+            if let Nature::Composite(Composite::Func(_, _, _, _, _, static_method)) = nature.deref()
+            {
+                return *static_method;
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if this `Nature` represents a field (not a method) marked with `#[tslink(readonly)]`.
+    pub fn is_field_readonly(&self) -> bool {
+        if let Nature::Referred(Referred::Field(_, context, nature, _)) = self {
+            !matches!(nature.deref(), Nature::Composite(Composite::Func(..)))
+                && context.as_readonly()
+        } else {
+            false
+        }
+    }
+
+    /// Returns the property name and the output type if this `Nature` represents a method
+    /// marked with `#[tslink(getter)]`.
+    ///
+    /// # Errors
+    /// Returns an error if the method name cannot be resolved.
+    pub fn get_getter(&self) -> Result<Option<(String, &Nature)>, E> {
+        if let Nature::Referred(Referred::Field(name, context, nature, _)) = self {
+            if let Nature::Composite(Composite::Func(_, _, Some(out), ..)) = nature.deref() {
+                if context.as_getter() {
+                    return Ok(Some((context.rename_method(name)?, out.deref())));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the property name and the type of the argument if this `Nature` represents a method
+    /// marked with `#[tslink(setter = "name")]`.
+    pub fn get_setter(&self) -> Option<(String, &Nature)> {
+        if let Nature::Referred(Referred::Field(_, context, nature, _)) = self {
+            if let Nature::Composite(Composite::Func(_, args, ..)) = nature.deref() {
+                if let (Some(property), Some(Nature::Referred(Referred::FuncArg(_, _, arg, _)))) =
+                    (context.get_setter(), args.first())
+                {
+                    return Some((property, arg.deref()));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns `true` if among given fields there is a setter for the given property.
+    pub fn has_setter(fields: &[Nature], property: &str) -> bool {
+        fields
+            .iter()
+            .any(|f| f.get_setter().is_some_and(|(name, _)| name == property))
+    }
+
+    /// Returns `true` if among given fields there is a getter for the given property.
+    ///
+    /// # Errors
+    /// Returns an error if the method name cannot be resolved.
+    pub fn has_getter(fields: &[Nature], property: &str) -> Result<bool, E> {
+        for field in fields.iter() {
+            if let Some((name, _)) = field.get_getter()? {
+                if name == property {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
     /// Returns `true` if this `Nature` represents a field that is marked as ignored in its `Context`.
     pub fn is_field_ignored(&self) -> bool {
         if let Nature::Referred(Referred::Field(name, context, _, _)) = self {
@@ -315,6 +462,79 @@ impl Nature {
         }
     }
 
+    /// Returns methods of a struct (fields with function type) or an enum.
+    pub fn get_methods(&self) -> Vec<&Nature> {
+        match self {
+            Self::Referred(Referred::Struct(_, _, fields)) => fields
+                .iter()
+                .filter(|field| {
+                    matches!(field, Nature::Referred(Referred::Field(_, _, nature, _))
+                        if matches!(nature.deref(), Nature::Composite(Composite::Func(..))))
+                })
+                .collect(),
+            Self::Referred(Referred::Enum(_, _, _, _, methods)) => methods.iter().collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Replaces methods of a struct or an enum; other natures aren't changed.
+    pub fn set_methods(&mut self, methods: Vec<Nature>) {
+        let is_method = |field: &Nature| {
+            matches!(field, Nature::Referred(Referred::Field(_, _, nature, _))
+                if matches!(nature.deref(), Nature::Composite(Composite::Func(..))))
+        };
+        match self {
+            Self::Referred(Referred::Struct(_, _, fields)) => {
+                fields.retain(|field| !is_method(field));
+                fields.extend(methods);
+            }
+            Self::Referred(Referred::Enum(_, _, _, _, current)) => {
+                *current = methods;
+            }
+            _ => {}
+        }
+    }
+
+    /// Collects names of all entities referred by this type (fields, arguments, variants, etc.).
+    pub fn refs(&self, names: &mut BTreeSet<String>) {
+        let nested: Vec<&Nature> = match self {
+            Self::Primitive(..) => vec![],
+            Self::Composite(composite) => match composite {
+                Composite::Array(nature) => vec![nature.as_ref()],
+                Composite::Vec(_, nature)
+                | Composite::HashMap(_, _, nature)
+                | Composite::Option(_, nature) => nature.iter().map(|n| n.as_ref()).collect(),
+                Composite::Tuple(_, natures) => natures.iter().collect(),
+                Composite::Result(_, ok, err, ..) => {
+                    ok.iter().chain(err.iter()).map(|n| n.as_ref()).collect()
+                }
+                Composite::Undefined(..) => vec![],
+                Composite::Func(_, args, out, ..) => {
+                    args.iter().chain(out.iter().map(|n| n.as_ref())).collect()
+                }
+            },
+            Self::Referred(referred) => match referred {
+                Referred::Ref(name, ..) => {
+                    names.insert(name.to_owned());
+                    vec![]
+                }
+                Referred::Struct(_, _, natures) | Referred::EnumVariant(_, _, natures, ..) => {
+                    natures.iter().collect()
+                }
+                Referred::Enum(_, _, variants, _, methods) => {
+                    variants.iter().chain(methods.iter()).collect()
+                }
+                Referred::TupleStruct(_, _, nature) => nature.iter().map(|n| n.as_ref()).collect(),
+                Referred::Func(_, _, nature)
+                | Referred::Field(_, _, nature, ..)
+                | Referred::FuncArg(_, _, nature, ..)
+                | Referred::Generic(_, nature)
+                | Referred::Constant(_, _, nature, ..) => vec![nature.as_ref()],
+            },
+        };
+        nested.iter().for_each(|nature| nature.refs(names));
+    }
+
     /// Retrieves the associated `Context` for this type, if applicable.
     ///
     /// # Errors
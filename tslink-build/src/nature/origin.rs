@@ -5,6 +5,7 @@ use crate::{
 
 use proc_macro2::TokenStream;
 use quote::quote;
+use serde::{Deserialize, Serialize};
 use std::{convert::From, str::FromStr};
 use syn::{Ident, ImplItemFn, ItemFn, PathSegment, ReturnType, Type, TypeTuple};
 
@@ -53,7 +54,7 @@ use syn::{Ident, ImplItemFn, ItemFn, PathSegment, ReturnType, Type, TypeTuple};
 /// [`ItemFn`]: syn::ItemFn
 /// [`TypeTokenStream`]: crate::TypeTokenStream
 /// [`TypeAsString`]: crate::TypeAsString
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OriginType(String);
 
 impl From<Ident> for OriginType {
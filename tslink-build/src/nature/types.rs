@@ -251,6 +251,7 @@ impl Extract<Type> for Nature {
 /// Extracts the function signature from an `impl` method (`ImplItemFn`) as `Composite::Func`.
 ///
 /// Parses function arguments, detects constructor status, handles async flags, and binds outputs.
+/// Associated functions without `self` receiver (which aren't constructors) are marked as static.
 impl Extract<&ImplItemFn> for Nature {
     fn extract(fn_item: &ImplItemFn, context: Context, cfg: &Config) -> Result<Nature, E> {
         let mut args = vec![];
@@ -280,12 +281,18 @@ impl Extract<&ImplItemFn> for Nature {
         } else {
             false
         } || context.as_constructor();
+        let receiver = fn_item
+            .sig
+            .inputs
+            .iter()
+            .any(|input| matches!(input, FnArg::Receiver(_)));
         Ok(Self::Composite(Composite::Func(
             OriginType::from(fn_item.clone()),
             args,
             out,
             fn_item.sig.asyncness.is_some(),
             constructor,
+            !receiver && !constructor,
         )))
     }
 }
@@ -328,6 +335,7 @@ impl Extract<&ItemFn> for Nature {
             out,
             fn_item.sig.asyncness.is_some(),
             constructor,
+            false,
         )))
     }
 }
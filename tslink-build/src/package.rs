@@ -0,0 +1,270 @@
+use crate::{
+    config::{
+        self,
+        cfg::{Backend, ModuleFormat},
+    },
+    error::E,
+    interpreter::{absolute, emit, relative_path},
+};
+use serde::Serialize;
+use serde_json::{json, ser::PrettyFormatter, Map, Serializer, Value as Json};
+use std::fs;
+use toml::Value;
+
+/// Version range of `zod` added to `dependencies` with `zod = true`.
+const ZOD_VERSION: &str = "^3.23.0";
+
+/// Extracts a string value from the given [`toml::Value`] under the specified key,
+/// returning an error if the key is missing or is not a string.
+///
+/// This function is typically used to retrieve `name` and `version` from `[package]` in `Cargo.toml`.
+///
+/// # Parameters
+/// - `value`: A reference to a TOML table (e.g., `[package]` section).
+/// - `key`: The key to extract (e.g., `"name"` or `"version"`).
+///
+/// # Returns
+/// The value as a `String` if present and valid.
+///
+/// # Errors
+/// Returns an `E::Other` variant if the key is missing or cannot be converted to a string.
+pub fn value(value: &Value, key: &str) -> Result<String, E> {
+    Ok(value
+        .get(key)
+        .ok_or(E::Other(format!(
+            "Fail to find \"{key}\" in [package] section of Cargo.toml"
+        )))?
+        .as_str()
+        .ok_or(E::Other(format!(
+            "Fail to convert \"{key}\" into string ([package] section of Cargo.toml)"
+        )))?
+        .to_string())
+}
+
+/// Copies string values and arrays of strings of the given keys from `[package]` of `Cargo.toml`
+/// into `package.json`, if `package.json` doesn't have such keys yet. Values inherited from the
+/// workspace (`license.workspace = true`) are skipped.
+fn fill_absent(json: &mut Map<String, Json>, package: &Value, keys: &[(&str, &str)]) {
+    for (cargo_key, json_key) in keys {
+        if json.contains_key(*json_key) {
+            continue;
+        }
+        match package.get(*cargo_key) {
+            Some(Value::String(s)) => {
+                json.insert(json_key.to_string(), Json::from(s.as_str()));
+            }
+            Some(Value::Array(items)) => {
+                let items: Vec<Json> = items
+                    .iter()
+                    .filter_map(|v| v.as_str().map(Json::from))
+                    .collect();
+                if items.is_empty() {
+                    continue;
+                }
+                if *json_key != "author" {
+                    json.insert(json_key.to_string(), Json::from(items));
+                    continue;
+                }
+                // Cargo keeps a list of authors; npm has one author and a list of contributors
+                json.insert(json_key.to_string(), items[0].clone());
+                if items.len() > 1 && !json.contains_key("contributors") {
+                    json.insert(String::from("contributors"), Json::from(&items[1..]));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Creates or updates a `package.json` file for the compiled Node.js module using data from `Cargo.toml` and configuration.
+///
+/// This function:
+/// - Reads `name` and `version` from `[package]` section of `Cargo.toml`.
+/// - Reads `node_mod_filename` and `node_mod_dist` from `[tslink]` or `[package.metadata.tslink]`.
+/// - Reads the existing `package.json` (`config.package_file`, by default in the target distribution
+///   folder) and keeps all its keys (scripts, dependencies, publishing metadata, etc.) in place.
+/// - Sets keys owned by tslink: `files`, `main`, `types` and, depending on `module_format`,
+///   `type` and `exports`; all paths are relative to the folder of `package.json`. Owned keys,
+///   which don't apply to the current format, are removed (`exports` with `cjs`, `"type": "module"`
+///   with `cjs` and `dual`).
+/// - Fills `name`, `version`, `module`, `description`, `license`, `repository`, `keywords` and
+///   `author` only if they are absent.
+/// - Adds `zod` to `dependencies` with `zod = true`, if it's absent.
+/// - With `backend = "wasm-bindgen"` adds files of the WebAssembly module to `files`, sets
+///   `sideEffects` for bundlers and, for ES modules, the `browser` entry.
+/// - Writes `package.json` only if content has been changed.
+///
+/// # Example Output
+/// ```ignore
+/// {
+///     "name": "your-package",
+///     "version": "0.1.0",
+///     "files": [
+///         "lib.node",
+///         "lib.js",
+///         "lib.d.ts"
+///     ],
+///     "module": "lib.js",
+///     "main": "lib.js",
+///     "types": "lib.d.ts"
+/// }
+/// ```
+///
+/// # Errors
+/// - If any required value is missing from configuration or Cargo metadata.
+/// - If the existing `package.json` isn't a valid JSON object.
+/// - If the output directory is invalid or file system operations fail.
+pub fn create() -> Result<(), E> {
+    let config = config::get()?;
+    let package = config
+        .get_cargo()
+        .get("package")
+        .ok_or(E::Other(String::from(
+            "Fail to find [package] in Cargo.toml",
+        )))?
+        .clone();
+    let name = value(&package, "name")?;
+    let dist = config.node_mod_dist.clone().ok_or(E::Other(String::from(
+        "No path to folder with node module. Set correct path in [tslink] of Cargo.toml; field \"node\"",
+    )))?;
+    let node_module = config
+        .node_mod_filename
+        .clone()
+        .ok_or(E::Other(String::from(
+            "No node module file name. Set correct path in [tslink] of Cargo.toml; field \"node\"",
+        )))?;
+    let module_format = config.module_format.clone();
+    let package_file = config.package_file.clone();
+    let package_dir = package_file.parent().unwrap_or(&dist);
+    let node_module = relative_path(package_dir, &dist.join(node_module))?;
+    let js = relative_path(package_dir, &config.js_file)?;
+    let cjs = relative_path(package_dir, &config.js_file.with_extension("cjs"))?;
+    let mjs = relative_path(package_dir, &config.js_file.with_extension("mjs"))?;
+    let dts = relative_path(package_dir, &config.dts_file)?;
+    let zod = config.zod;
+    let backend = config.backend.clone();
+    drop(config);
+    let current = absolute(&package_file)?;
+    let mut json = if current.exists() {
+        match serde_json::from_str(&fs::read_to_string(&current)?)? {
+            Json::Object(json) => json,
+            _ => {
+                return Err(E::Other(format!(
+                    "Fail to update {}: content isn't a JSON object",
+                    package_file.display()
+                )))
+            }
+        }
+    } else {
+        Map::new()
+    };
+    if !json.contains_key("name") {
+        json.insert(String::from("name"), Json::from(name));
+    }
+    fill_absent(
+        &mut json,
+        &package,
+        &[
+            ("version", "version"),
+            ("description", "description"),
+            ("license", "license"),
+            ("repository", "repository"),
+            ("keywords", "keywords"),
+            ("authors", "author"),
+        ],
+    );
+    if module_format == ModuleFormat::Esm {
+        json.insert(String::from("type"), Json::from("module"));
+    } else if json.get("type").and_then(|v| v.as_str()) == Some("module") {
+        // lib.js is a CommonJS module with "cjs" and "dual"
+        json.remove("type");
+    }
+    // wasm-bindgen generates the module "name.js" next to "name_bg.wasm" (and "name_bg.js"
+    // for bundlers); the module and its snippets have side effects (loading of WebAssembly)
+    let side_effects = json!([format!("./{node_module}"), "./snippets/*"]);
+    let native_files = if backend == Backend::WasmBindgen {
+        let stem = node_module
+            .strip_suffix(".js")
+            .unwrap_or(&node_module)
+            .to_owned();
+        json.insert(String::from("sideEffects"), side_effects);
+        vec![
+            node_module.clone(),
+            format!("{stem}_bg.js"),
+            format!("{stem}_bg.wasm"),
+        ]
+    } else {
+        if json.get("sideEffects") == Some(&side_effects) {
+            json.remove("sideEffects");
+        }
+        vec![node_module]
+    };
+    let (files, module) = match module_format {
+        ModuleFormat::Cjs | ModuleFormat::Esm => {
+            ([native_files, vec![js.clone(), dts.clone()]].concat(), &js)
+        }
+        ModuleFormat::Dual => (
+            [
+                native_files,
+                vec![js.clone(), cjs.clone(), mjs.clone(), dts.clone()],
+            ]
+            .concat(),
+            &mjs,
+        ),
+    };
+    if zod {
+        // lib.js requires zod at runtime; the version of an existing dependency is kept
+        if let Json::Object(dependencies) = json
+            .entry("dependencies")
+            .or_insert_with(|| Json::Object(Map::new()))
+        {
+            dependencies
+                .entry("zod")
+                .or_insert_with(|| Json::from(ZOD_VERSION));
+        }
+    }
+    json.insert(String::from("files"), Json::from(files));
+    if !json.contains_key("module") {
+        json.insert(String::from("module"), Json::from(module.as_str()));
+    }
+    json.insert(String::from("main"), Json::from(js.as_str()));
+    json.insert(String::from("types"), Json::from(dts.as_str()));
+    if (module_format != ModuleFormat::Esm || backend != Backend::WasmBindgen)
+        && json.get("browser").and_then(|v| v.as_str()) == Some(js.as_str())
+    {
+        json.remove("browser");
+    }
+    match module_format {
+        ModuleFormat::Cjs => {
+            json.remove("exports");
+        }
+        ModuleFormat::Esm => {
+            json.insert(
+                String::from("exports"),
+                json!({ ".": { "types": format!("./{dts}"), "import": format!("./{js}") } }),
+            );
+            if backend == Backend::WasmBindgen {
+                json.insert(String::from("browser"), Json::from(js.as_str()));
+            }
+        }
+        ModuleFormat::Dual => {
+            json.insert(
+                String::from("exports"),
+                json!({ ".": {
+                    "types": format!("./{dts}"),
+                    "import": format!("./{mjs}"),
+                    "require": format!("./{cjs}")
+                } }),
+            );
+        }
+    }
+    let mut buf = Vec::new();
+    let mut serializer =
+        Serializer::with_formatter(&mut buf, PrettyFormatter::with_indent(b"    "));
+    Json::Object(json).serialize(&mut serializer)?;
+    emit(
+        &package_file,
+        &format!("{}\n", String::from_utf8_lossy(&buf)),
+    )?;
+    Ok(())
+}
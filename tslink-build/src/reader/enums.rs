@@ -0,0 +1,189 @@
+use super::resolve_self;
+use crate::{
+    config::Config,
+    context::Context,
+    error::E,
+    interpreter::{enum_method_name, serialize_name},
+    modificator,
+    nature::{Composite, Extract, ExtractGenerics, Nature, Referred},
+};
+use syn::{punctuated::Punctuated, token::Comma, Fields, FnArg, ImplItem, ItemFn};
+
+/// Parses the fields of an enum variant into a list of `Nature` values.
+///
+/// Supports named (`struct-like`), unnamed (`tuple-like`), and unit variants.
+///
+/// # Parameters
+/// - `fields`: The fields of the enum variant (`syn::Fields`).
+/// - `context`: The current macro context for tracking attributes and scope.
+/// - `cfg`: The global configuration settings.
+///
+/// # Returns
+/// A list of `Nature` instances representing the field types of the variant.
+///
+/// # Errors
+/// Returns an error if any field type fails to be parsed or resolved.
+fn read_variant(fields: &Fields, context: Context, cfg: &Config) -> Result<Vec<Nature>, E> {
+    let mut values: Vec<Nature> = vec![];
+    match fields {
+        Fields::Named(ref fields) => {
+            for field in fields.named.iter() {
+                let name = field.ident.clone().unwrap();
+                values.push(Nature::Referred(Referred::Field(
+                    serialize_name(name.to_string()),
+                    context.clone(),
+                    Box::new(Nature::extract(&field.ty, context.clone(), cfg)?),
+                    None,
+                )));
+            }
+        }
+        Fields::Unnamed(ref fields) => {
+            for field in fields.unnamed.iter() {
+                values.push(Nature::extract(&field.ty, context.clone(), cfg)?);
+            }
+        }
+        Fields::Unit => {}
+    }
+    Ok(values)
+}
+
+/// Reads all variants of an enum and attaches them to the given parent as `Referred::EnumVariant`.
+///
+/// This function filters out ignored variants, parses their fields via `read_variant`, and binds
+/// each as a structured representation suitable for TypeScript generation.
+///
+/// # Parameters
+/// - `variants`: The list of enum variants to process.
+/// - `parent`: The parent `Nature`, expected to be a `Referred::Enum` into which the variants are bound.
+/// - `context`: The current macro context.
+/// - `cfg`: Global configuration settings for parsing behavior.
+///
+/// # Errors
+/// Returns an error if field parsing fails or variant binding encounters an issue.
+pub fn read(
+    variants: &Punctuated<syn::Variant, Comma>,
+    parent: &mut Nature,
+    context: Context,
+    cfg: &Config,
+) -> Result<(), E> {
+    let mut fields: Vec<(String, Vec<Nature>)> = vec![];
+    for variant in variants {
+        let name = variant.ident.to_string();
+        if context.is_ignored(&name) {
+            continue;
+        }
+        fields.push((name, read_variant(&variant.fields, context.clone(), cfg)?));
+    }
+    let not_flat = fields.iter().any(|(_, v)| !v.is_empty());
+    for (name, values) in fields {
+        parent.bind(Nature::Referred(Referred::EnumVariant(
+            serialize_name(&name),
+            context.clone(),
+            values,
+            !not_flat,
+            cfg.enum_representation.clone(),
+        )))?;
+    }
+    Ok(())
+}
+
+/// Parses methods from an `impl` block of an enum and binds them to the enum as functions of its namespace.
+///
+/// Enum values cannot carry methods on JavaScript side, so each method becomes a standalone function:
+/// - a method with `self` receiver gets the enum value as the first argument `value`;
+/// - `Self` in arguments and output is replaced with the enum type.
+///
+/// Each method is exported from the native module by a generated function named as
+/// `{enum}_{method}` in snake case (see `modificator::enum_method`); methods themselves aren't
+/// modified. Own `#[tslink]` attributes are removed from methods, so they aren't read again as
+/// standalone functions.
+///
+/// # Parameters
+/// - `items`: List of `ImplItem`s from the `impl` block.
+/// - `parent`: The parent `Nature` (`Referred::Enum`).
+/// - `enum_name`: Name of the enum.
+/// - `enum_context`: Context of the enum.
+/// - `cfg`: Global configuration settings.
+///
+/// # Returns
+/// Functions, which export methods from the native module; they should be placed next to the
+/// `impl` block.
+///
+/// # Errors
+/// - If method parsing or binding fails.
+/// - If context resolution fails.
+pub fn read_impl(
+    items: &mut [ImplItem],
+    parent: &mut Nature,
+    enum_name: &str,
+    enum_context: Context,
+    cfg: &Config,
+) -> Result<Vec<ItemFn>, E> {
+    let mut exported = vec![];
+    for item in items.iter_mut() {
+        if let ImplItem::Fn(fn_item) = item {
+            let mut context = Context::try_from_or_default(&fn_item.attrs)?;
+            fn_item.attrs.retain(|attr| !Context::is_own_attr(attr));
+            context.set_parent(enum_context.clone());
+            context.add_generics(Nature::extract_generics(&fn_item.sig.generics, cfg)?);
+            if context.ignore_self() {
+                continue;
+            }
+            let name = fn_item.sig.ident.to_string();
+            if context.is_ignored(&name) {
+                continue;
+            }
+            let fn_nature = Nature::extract(&*fn_item, context.clone(), cfg)?;
+            let (origin, args, out, asyncness) =
+                if let Nature::Composite(Composite::Func(origin, args, out, asyncness, ..)) =
+                    fn_nature
+                {
+                    (origin, args, out, asyncness)
+                } else {
+                    return Err(E::Parsing(format!("Fail to parse method \"{name}\"")));
+                };
+            let mut args = args
+                .into_iter()
+                .map(|arg| resolve_self(arg, enum_name, &context))
+                .collect::<Vec<Nature>>();
+            if fn_item
+                .sig
+                .inputs
+                .iter()
+                .any(|input| matches!(input, FnArg::Receiver(_)))
+            {
+                args.insert(
+                    0,
+                    Nature::Referred(Referred::FuncArg(
+                        String::from("value"),
+                        context.clone(),
+                        Box::new(Nature::Referred(Referred::Ref(
+                            enum_name.to_owned(),
+                            Some(context.clone()),
+                        ))),
+                        None,
+                    )),
+                );
+            }
+            let out = out.map(|out| Box::new(resolve_self(*out, enum_name, &context)));
+            parent.bind(Nature::Referred(Referred::Field(
+                serialize_name(&name),
+                context.clone(),
+                Box::new(Nature::Composite(Composite::Func(
+                    origin, args, out, asyncness, false, true,
+                ))),
+                context.get_bound(&name),
+            )))?;
+            let native_name = enum_method_name(enum_name, &serialize_name(&name));
+            exported.push(modificator::enum_method(
+                fn_item,
+                enum_name,
+                &native_name,
+                &context.rename_method(&native_name)?,
+                &context,
+                cfg,
+            )?);
+        }
+    }
+    Ok(exported)
+}
@@ -0,0 +1,334 @@
+mod enums;
+mod structs;
+
+use crate::{
+    config::{self, Config},
+    context::Context,
+    error::E,
+    interpreter::{self, serialize_name},
+    modificator,
+    nature::{Composite, Extract, ExtractGenerics, Nature, Natures, Referred},
+    registry,
+};
+use quote::ToTokens;
+use std::ops::Deref;
+use syn::{fold::Fold, Fields, Item, ItemConst, ItemEnum, ItemFn, ItemStruct, LitFloat, LitInt};
+
+/// Removes suffixes of numeric literals (`1.5f64` → `1.5`, `10u8` → `10`), which aren't valid in
+/// TypeScript/JavaScript, from the value of a constant.
+struct Unsuffixed;
+
+impl Fold for Unsuffixed {
+    fn fold_lit_int(&mut self, lit: LitInt) -> LitInt {
+        if lit.suffix().is_empty() {
+            lit
+        } else {
+            LitInt::new(lit.base10_digits(), lit.span())
+        }
+    }
+
+    fn fold_lit_float(&mut self, lit: LitFloat) -> LitFloat {
+        if lit.suffix().is_empty() {
+            lit
+        } else {
+            LitFloat::new(lit.base10_digits(), lit.span())
+        }
+    }
+}
+
+/// Replaces `Self` in arguments and outputs of methods with a reference to the implemented
+/// struct or enum (including nested types).
+fn resolve_self(nature: Nature, entity: &str, context: &Context) -> Nature {
+    let resolve = |nature: Box<Nature>| Box::new(resolve_self(*nature, entity, context));
+    match nature {
+        Nature::Referred(Referred::Ref(name, _)) if name == "Self" => {
+            Nature::Referred(Referred::Ref(entity.to_owned(), Some(context.clone())))
+        }
+        Nature::Referred(Referred::FuncArg(name, arg_context, nature, binding)) => {
+            Nature::Referred(Referred::FuncArg(
+                name,
+                arg_context,
+                resolve(nature),
+                binding,
+            ))
+        }
+        Nature::Composite(Composite::Array(nature)) => {
+            Nature::Composite(Composite::Array(resolve(nature)))
+        }
+        Nature::Composite(Composite::Vec(origin, nature)) => {
+            Nature::Composite(Composite::Vec(origin, nature.map(resolve)))
+        }
+        Nature::Composite(Composite::HashMap(origin, key, nature)) => {
+            Nature::Composite(Composite::HashMap(origin, key, nature.map(resolve)))
+        }
+        Nature::Composite(Composite::Tuple(origin, natures)) => {
+            Nature::Composite(Composite::Tuple(
+                origin,
+                natures
+                    .into_iter()
+                    .map(|nature| resolve_self(nature, entity, context))
+                    .collect(),
+            ))
+        }
+        Nature::Composite(Composite::Option(origin, nature)) => {
+            Nature::Composite(Composite::Option(origin, nature.map(resolve)))
+        }
+        Nature::Composite(Composite::Func(
+            origin,
+            args,
+            out,
+            asyncness,
+            constructor,
+            static_method,
+        )) => Nature::Composite(Composite::Func(
+            origin,
+            args.into_iter()
+                .map(|nature| resolve_self(nature, entity, context))
+                .collect(),
+            out.map(resolve),
+            asyncness,
+            constructor,
+            static_method,
+        )),
+        Nature::Composite(Composite::Result(
+            origin,
+            res,
+            err,
+            exception_suppression,
+            asyncness,
+        )) => Nature::Composite(Composite::Result(
+            origin,
+            res.map(resolve),
+            err.map(resolve),
+            exception_suppression,
+            asyncness,
+        )),
+        nature => nature,
+    }
+}
+
+/// Main entry point for reading and interpreting a Rust item (`struct`, `enum`, `fn`, `impl`, `const`) into a typed [`Nature`] representation.
+///
+/// This function is responsible for analyzing the annotated Rust item, extracting structural type information,
+/// and inserting the result into the shared [`Natures`] registry. Based on the item's kind, the function performs:
+///
+/// - For `struct` and `tuple struct`: Collects fields, determines representation, and stores as `Referred::Struct` or `Referred::TupleStruct`.
+/// - For `enum`: Parses variants and stores as `Referred::Enum`.
+/// - For `fn`: Extracts function signature (unless it's a method or constructor), stores as `Referred::Func`.
+/// - For `impl`: Merges methods into the previously defined struct or enum (generic and path-qualified
+///   self types are resolved by the last path segment; type parameters of the block are passed to methods).
+///   Methods of enums are exported from the native module by generated functions, which are returned.
+/// - For `const`: Stores as `Referred::Constant`.
+///
+/// If output generation is enabled (`io_allowed`), the function also merges entities into the persistent
+/// registry (`registry::sync`) and invokes TypeScript/JavaScript generation for all entities of the crate
+/// via `interpreter::generate` (unless generation is deferred to `tslink::finalize!()`).
+///
+/// # Parameters
+/// - `item`: The input Rust item to analyze and transform.
+/// - `natures`: The registry to collect parsed types (`Natures`).
+/// - `context`: Macro-level context, including configuration and attributes.
+/// - `cfg`: Global generation configuration.
+///
+/// # Returns
+/// Generated functions, which should be placed next to the item (exported methods of enums).
+///
+/// # Errors
+/// - Returns an error if the item is unsupported, malformed, or already exists in the registry.
+/// - Also returns detailed errors if generation of `.ts`, `.d.ts`, or `.js` files fails.
+///
+/// # Side Effects
+/// - May perform file I/O if `cfg.io_allowed == true`, generation isn't deferred and target paths are configured.
+///
+/// # Note
+/// This function is the root of all type discovery and macro processing —
+/// it's designed to be called once per `Item` encountered by a derive or attribute macro.
+pub fn read(
+    item: &mut Item,
+    natures: &mut Natures,
+    mut context: Context,
+    cfg: &Config,
+) -> Result<Vec<ItemFn>, E> {
+    let io_allowed = config::get()?.io_allowed;
+    let item_ref = item.clone();
+    let mut exported: Vec<ItemFn> = vec![];
+    match item {
+        Item::Struct(item_struct) => {
+            let ItemStruct { ident, fields, .. } = item_struct;
+            let name = ident.to_string();
+            if natures.contains(&name) {
+                Err(E::EntityExist(quote::quote! { #item }.to_string()))
+            } else {
+                context.add_generics(Nature::extract_generics(&item_struct.generics, cfg)?);
+                let mut nature = if matches!(fields, Fields::Unnamed(..)) {
+                    Nature::Referred(Referred::TupleStruct(
+                        serialize_name(&name),
+                        context.clone(),
+                        None,
+                    ))
+                } else {
+                    Nature::Referred(Referred::Struct(
+                        serialize_name(&name),
+                        context.clone(),
+                        vec![],
+                    ))
+                };
+                structs::read_fields(fields, &mut nature, context.clone(), cfg)?;
+                natures.insert(&name, nature, context.get_module())
+            }
+        }
+        Item::Enum(item_enum) => {
+            let ItemEnum {
+                ident, variants, ..
+            } = item_enum;
+            let name = ident.to_string();
+            if natures.contains(&name) {
+                Err(E::EntityExist(name))
+            } else {
+                let mut nature = Nature::Referred(Referred::Enum(
+                    serialize_name(&name),
+                    context.clone(),
+                    vec![],
+                    cfg.enum_representation.clone(),
+                    vec![],
+                ));
+                enums::read(variants, &mut nature, context.clone(), cfg)?;
+                natures.insert(&name, nature, context.get_module())
+            }
+        }
+        Item::Fn(item_fn) => {
+            if structs::is_method(item_fn) {
+                return Ok(vec![]);
+            }
+            context.add_generics(Nature::extract_generics(&item_fn.sig.generics, cfg)?);
+            if let Nature::Composite(Composite::Func(_, _, _, _, constructor, _)) =
+                Nature::extract(&*item_fn, context.clone(), cfg)?
+            {
+                if constructor {
+                    return Ok(vec![]);
+                }
+            }
+            let name = item_fn.sig.ident.to_string();
+            if natures.contains(&name) {
+                Err(E::EntityExist(name))
+            } else {
+                let fn_nature = Nature::extract(&*item_fn, context.clone(), cfg)?;
+                let _ = natures.insert(
+                    &name,
+                    Nature::Referred(Referred::Func(
+                        serialize_name(&name),
+                        context.clone(),
+                        Box::new(fn_nature.clone()),
+                    )),
+                    context.get_module(),
+                );
+                modificator::bind_fn(item_fn, &name, &context, &fn_nature)?;
+                Ok(())
+            }
+        }
+        Item::Impl(item_impl) => {
+            // Self type can be path-qualified (`crate::model::User`) or generic (`Cache<T>`),
+            // so the type is identified by the ident of the last path segment
+            let ident = match *item_impl.self_ty {
+                syn::Type::Path(ref p) => p.path.segments.last().map(|segment| &segment.ident),
+                _ => None,
+            };
+            let struct_name = if let Some(ident) = ident {
+                ident.to_string()
+            } else {
+                return Err(E::FailIdentify);
+            };
+            // Type parameters of impl block (`impl<T> Cache<T>`) are available for all methods
+            let impl_generics = Nature::extract_generics(&item_impl.generics, cfg)?;
+            context.add_generics(impl_generics.clone());
+            let known = natures
+                .get_entity(&struct_name)
+                .map(|nature| nature.get_methods().len())
+                .unwrap_or_default();
+            let read = if let Some(nature) = natures.get_mut(
+                &struct_name,
+                Some(Nature::Referred(Referred::Struct(
+                    serialize_name(&struct_name),
+                    context.clone(),
+                    vec![],
+                ))),
+                context.get_module(),
+            ) {
+                if let Nature::Referred(Referred::Struct(_, struct_context, _)) = nature.deref() {
+                    let mut struct_context = struct_context.clone();
+                    struct_context.add_generics(impl_generics);
+                    structs::read_impl(
+                        &mut item_impl.items,
+                        nature,
+                        &struct_name,
+                        struct_context,
+                        context.clone(),
+                        cfg,
+                    )
+                } else if let Nature::Referred(Referred::Enum(_, enum_context, ..)) = nature.deref()
+                {
+                    let mut enum_context = enum_context.clone();
+                    enum_context.add_generics(impl_generics);
+                    enums::read_impl(
+                        &mut item_impl.items,
+                        nature,
+                        &struct_name,
+                        enum_context,
+                        cfg,
+                    )
+                    .map(|functions| exported = functions)
+                } else {
+                    Err(E::NotFoundStruct)
+                }
+            } else {
+                Err(E::NotFoundStruct)
+            };
+            read?;
+            // Methods of the block are recorded for the persistent registry
+            let methods = natures
+                .get_entity(&struct_name)
+                .map(|nature| {
+                    nature
+                        .get_methods()
+                        .into_iter()
+                        .skip(known)
+                        .filter_map(|method| match method {
+                            Nature::Referred(Referred::Field(name, ..)) => Some(name.to_owned()),
+                            _ => None,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            natures.add_methods(&struct_name, methods);
+            Ok(())
+        }
+        Item::Const(item_const) => {
+            let ItemConst {
+                ident, ty, expr, ..
+            } = item_const;
+            let name = ident.to_string();
+            if natures.contains(&name) {
+                Err(E::EntityExist(name))
+            } else {
+                let nature = Nature::Referred(Referred::Constant(
+                    serialize_name(ident.to_string()),
+                    context.to_owned(),
+                    Box::new(Nature::extract(ty.as_ref(), context.to_owned(), cfg)?),
+                    Unsuffixed
+                        .fold_expr(expr.as_ref().clone())
+                        .into_token_stream()
+                        .to_string(),
+                ));
+                natures.insert(&name, nature, context.get_module())
+            }
+        }
+        _ => Ok(()),
+    }?;
+    // With deferred generation files are generated once by `tslink::finalize!()`
+    if io_allowed && !cfg.deferred_generation {
+        registry::sync(natures, false)
+            .and_then(|natures| interpreter::generate(&natures, cfg))
+            .map_err(|e| E::Compiler(syn::Error::new_spanned(item_ref, e.to_string())))?;
+    }
+    Ok(exported)
+}
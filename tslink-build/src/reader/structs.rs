@@ -0,0 +1,233 @@
+use super::resolve_self;
+use crate::{
+    config::Config,
+    context::Context,
+    error::E,
+    interpreter::serialize_name,
+    modificator,
+    nature::{Composite, Extract, ExtractGenerics, Nature, Referred},
+};
+use syn::{Fields, ImplItem, ItemFn, Visibility};
+
+/// Returns `true` if the given function is an instance method (i.e., has a `self` receiver).
+///
+/// # Parameters
+/// - `item_fn`: A parsed function item (`syn::ItemFn`).
+///
+/// # Returns
+/// `true` if the function is a method (`fn self(...)`), `false` if it is a static function.
+pub fn is_method(item_fn: &ItemFn) -> bool {
+    item_fn
+        .sig
+        .inputs
+        .iter()
+        .any(|input| matches!(input, syn::FnArg::Receiver(_)))
+}
+
+/// Returns `true` if a non-`pub` field or method of a class should be skipped.
+///
+/// Private members are skipped only for structs represented as classes, as long as
+/// `skip_private` is enabled in the configuration and the member isn't marked with
+/// `#[tslink(include)]`.
+///
+/// # Errors
+/// Returns an error if the skipped member has own `#[tslink]` attributes (`annotated`), because
+/// these attributes would be dropped silently.
+fn is_private_skipped(
+    name: &str,
+    vis: &Visibility,
+    annotated: bool,
+    context: &Context,
+    cfg: &Config,
+) -> Result<bool, E> {
+    if !cfg.skip_private
+        || !context.as_class()
+        || context.as_include()
+        || matches!(vis, Visibility::Public(..))
+    {
+        return Ok(false);
+    }
+    if annotated {
+        return Err(E::Parsing(format!(
+            "\"{name}\" has #[tslink] attributes, but isn't pub and would be skipped (skip_private = true); make it pub or add #[tslink(include)]"
+        )));
+    }
+    Ok(true)
+}
+
+/// Parses the fields of a Rust struct or tuple struct and binds them to the given parent `Nature`.
+///
+/// For named fields (`struct Foo { ... }`), each field is resolved into a `Referred::Field` with proper context,
+/// including attribute processing and optional bindings. Own `#[tslink]` attributes are removed from fields.
+/// Non-`pub` fields of classes are skipped (see `is_private_skipped`).
+///
+/// For tuple structs (`struct Foo(Type);`), only the first unnamed field is parsed and bound.
+///
+/// # Parameters
+/// - `fields`: The struct fields (`syn::Fields`).
+/// - `parent`: The parent `Nature`, typically a `Referred::Struct` or `TupleStruct`.
+/// - `parent_context`: Inherited macro context from the parent type.
+/// - `cfg`: The global configuration settings.
+///
+/// # Errors
+/// - If field type extraction fails.
+/// - If the field layout is unsupported.
+/// - If ignored fields are listed in attributes but not found in the struct definition.
+pub fn read_fields(
+    fields: &mut Fields,
+    parent: &mut Nature,
+    parent_context: Context,
+    cfg: &Config,
+) -> Result<(), E> {
+    if let Fields::Named(ref mut fields) = fields {
+        for field in fields.named.iter_mut() {
+            let mut context = Context::try_from_or_default(&field.attrs)?;
+            let annotated = field.attrs.iter().any(Context::is_own_attr);
+            // Attribute macros cannot be applied on fields, so own attributes should be removed
+            field.attrs.retain(|attr| !Context::is_own_attr(attr));
+            context.set_parent(parent_context.clone());
+            if context.ignore_self() {
+                continue;
+            }
+            let name = field.ident.clone().unwrap();
+            // Fields from the ignore list are bound anyway and filtered out on rendering
+            if !parent_context.is_ignored(&name.to_string())
+                && is_private_skipped(&name.to_string(), &field.vis, annotated, &context, cfg)?
+            {
+                continue;
+            }
+            parent.bind(Nature::Referred(Referred::Field(
+                serialize_name(name.to_string()),
+                context.clone(),
+                Box::new(Nature::extract(&field.ty, context.clone(), cfg)?),
+                context.get_bound(&name.to_string()),
+            )))?;
+        }
+        parent.check_ignored_fields()?;
+    } else if let Fields::Unnamed(ref fields) = fields {
+        if let Some(field) = fields.unnamed.first() {
+            let mut context = Context::default();
+            context.set_parent(parent_context.clone());
+            parent.bind(Nature::Referred(Referred::Field(
+                String::new(),
+                context.clone(),
+                Box::new(Nature::extract(&field.ty, context.clone(), cfg)?),
+                None,
+            )))?;
+        }
+    } else {
+        return Err(E::NotSupported(String::from("Unsupported type of fields")));
+    }
+    Ok(())
+}
+
+/// Validates a method marked as a getter (`#[tslink(getter)]`) or a setter (`#[tslink(setter = "field")]`).
+///
+/// A getter should be a non-async instance method without arguments, which returns a value;
+/// a setter should be a non-async instance method with exactly one argument.
+///
+/// # Errors
+/// Returns an error if the method cannot be represented as a property accessor.
+fn check_accessor(name: &str, context: &Context, fn_nature: &Nature) -> Result<(), E> {
+    let (getter, setter) = (context.as_getter(), context.get_setter().is_some());
+    if !getter && !setter {
+        return Ok(());
+    }
+    if getter && setter {
+        return Err(E::Parsing(format!(
+            "Method \"{name}\" cannot be getter and setter at the same time"
+        )));
+    }
+    if let Nature::Composite(Composite::Func(_, args, out, asyncness, constructor, static_method)) =
+        fn_nature
+    {
+        if *asyncness || *constructor || *static_method {
+            return Err(E::Parsing(format!(
+                "Only not async instance method can be used as getter/setter; method: \"{name}\""
+            )));
+        }
+        if getter && !args.is_empty() {
+            return Err(E::Parsing(format!(
+                "Getter \"{name}\" should not have arguments"
+            )));
+        }
+        let returns = out.as_deref().is_some_and(|out| {
+            !matches!(out, Nature::Composite(Composite::Result(_, None, None, ..)))
+        });
+        if getter && !returns {
+            return Err(E::Parsing(format!(
+                "Getter \"{name}\" should return a value"
+            )));
+        }
+        if setter && args.len() != 1 {
+            return Err(E::Parsing(format!(
+                "Setter \"{name}\" should have exactly one argument"
+            )));
+        }
+        Ok(())
+    } else {
+        Err(E::Parsing(format!("Fail to parse method \"{name}\"")))
+    }
+}
+
+/// Parses and binds methods from an `impl` block into the parent type as fields with function types.
+///
+/// For each method:
+/// - Applies attribute context (`#[tslink(...)]`)
+/// - Detects and propagates generics
+/// - Extracts function type (`Self` is replaced with the struct) and associates it as `Referred::Field`
+/// - Skips non-`pub` methods of classes (see `is_private_skipped`)
+/// - Validates getters and setters
+/// - Applies bound name mapping and invokes optional post-processing
+/// - Removes own `#[tslink]` attributes, so the method isn't read again as a standalone function
+///
+/// # Parameters
+/// - `items`: List of `ImplItem`s from the `impl` block.
+/// - `parent`: The parent `Nature` (typically a `Referred::Struct`).
+/// - `struct_name`: Name of the struct; `Self` in arguments and outputs is replaced with it.
+/// - `struct_context`: Context of the struct being implemented.
+/// - `_parent_context`: Optional context from the outer scope (not used here).
+/// - `cfg`: Global configuration settings.
+///
+/// # Errors
+/// - If method parsing or binding fails.
+/// - If context resolution fails.
+pub fn read_impl(
+    items: &mut [ImplItem],
+    parent: &mut Nature,
+    struct_name: &str,
+    struct_context: Context,
+    _parent_context: Context,
+    cfg: &Config,
+) -> Result<(), E> {
+    for item in items.iter_mut() {
+        if let ImplItem::Fn(fn_item) = item {
+            let mut context = Context::try_from_or_default(&fn_item.attrs)?;
+            let annotated = fn_item.attrs.iter().any(Context::is_own_attr);
+            // Method is fully handled here. Without own attributes it will not be expanded
+            // once again as a standalone function (which is the case for static methods).
+            fn_item.attrs.retain(|attr| !Context::is_own_attr(attr));
+            context.set_parent(struct_context.clone());
+            context.add_generics(Nature::extract_generics(&fn_item.sig.generics, cfg)?);
+            if context.ignore_self() {
+                continue;
+            }
+            let name = fn_item.sig.ident.to_string();
+            if context.is_ignored(&name)
+                || is_private_skipped(&name, &fn_item.vis, annotated, &context, cfg)?
+            {
+                continue;
+            }
+            let fn_nature = Nature::extract(&*fn_item, context.clone(), cfg)?;
+            check_accessor(&name, &context, &fn_nature)?;
+            parent.bind(Nature::Referred(Referred::Field(
+                serialize_name(&name),
+                context.clone(),
+                Box::new(resolve_self(fn_nature.clone(), struct_name, &context)),
+                context.get_bound(&name),
+            )))?;
+            modificator::bind_impl_fn(fn_item, &name, &context, &fn_nature)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,604 @@
+use crate::{
+    config,
+    error::E,
+    interpreter::{absolute, header, serialize_name, write_if_changed, Journal},
+    nature::{Nature, NatureDef, Natures, Referred},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+};
+use syn::{ImplItem, Item};
+
+const REGISTRY_FOLDER: &str = "tslink";
+
+/// Method of an entity stored in the registry.
+#[derive(Serialize, Deserialize, Clone)]
+struct Method {
+    name: String,
+    /// Source file of the `impl` block of the method, if known.
+    source: Option<PathBuf>,
+}
+
+/// Entity stored in the registry.
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    /// Path of the entity in the crate (`crate::model::User`); identifies the entry.
+    path: String,
+    /// Name of the entity.
+    name: String,
+    /// Content hash of the entity; used to detect changes.
+    hash: String,
+    /// Source file of the entity, if known.
+    source: Option<PathBuf>,
+    /// Module of the entity (`#[tslink(module = "...")]`).
+    module: Option<String>,
+    /// Methods of the entity (struct or enum) read from `impl` blocks.
+    #[serde(default)]
+    methods: Vec<Method>,
+    nature: Nature,
+}
+
+impl Entry {
+    fn new(
+        path: &str,
+        name: &str,
+        nature: Nature,
+        module: &Option<String>,
+        source: &Option<PathBuf>,
+        methods: Vec<Method>,
+    ) -> Result<Self, E> {
+        // The hash is stored in the registry, so it should be stable between versions of Rust
+        let hash = header::hash(&serde_json::to_string(&(&nature, module, &methods))?);
+        Ok(Self {
+            path: path.to_owned(),
+            name: name.to_owned(),
+            hash,
+            source: source.clone(),
+            module: module.clone(),
+            methods,
+            nature,
+        })
+    }
+
+    /// Returns the source file of the method; methods without a record belong to the source
+    /// of the entity.
+    fn method_source(&self, name: &str) -> Option<&PathBuf> {
+        self.methods
+            .iter()
+            .find(|method| method.name == name)
+            .map_or(self.source.as_ref(), |method| method.source.as_ref())
+    }
+}
+
+/// Returns the name of a method (a field of a struct or a method of an enum).
+fn method_name(method: &Nature) -> Option<&str> {
+    if let Nature::Referred(Referred::Field(name, ..)) = method {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Returns the module path of the source file (`crate::model::user` for `src/model/user.rs`).
+/// Entities without a source file belong to the root of the crate.
+fn module_path(source: &Option<PathBuf>, manifest_dir: &Path) -> Result<String, E> {
+    let Some(source) = source else {
+        return Ok(String::from("crate"));
+    };
+    let source = absolute(source)?.with_extension("");
+    let relative = source
+        .strip_prefix(manifest_dir.join("src"))
+        .or_else(|_| source.strip_prefix(manifest_dir))
+        .unwrap_or(&source);
+    let mut segments = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+    if segments
+        .last()
+        .is_some_and(|last| ["lib", "main", "mod"].contains(&last.as_str()))
+    {
+        segments.pop();
+    }
+    segments.insert(0, String::from("crate"));
+    Ok(segments.join("::"))
+}
+
+/// Returns the path of the entity in the crate. Entities declared in inline modules
+/// (`mod inner { ... }`) get the path of the inline module (`crate::model::inner::User`).
+fn entity_path(
+    name: &str,
+    source: &Option<PathBuf>,
+    sources: &mut HashMap<PathBuf, Option<Declared>>,
+    manifest_dir: &Path,
+) -> Result<String, E> {
+    if let Some(source) = source.as_ref().filter(|source| source.exists()) {
+        if let Some(path) = Declared::cached(sources, source, manifest_dir)?.and_then(|declared| {
+            declared
+                .paths
+                .iter()
+                .find(|path| path.rsplit("::").next() == Some(name))
+        }) {
+            return Ok(path.to_owned());
+        }
+    }
+    Ok(format!("{}::{name}", module_path(source, manifest_dir)?))
+}
+
+/// Items declared with `#[tslink]` in a source file.
+#[derive(Default)]
+struct Declared {
+    /// Paths of declared entities (`crate::model::User`).
+    paths: HashSet<String>,
+    /// Names of types with `#[tslink]` impl blocks and names of their methods.
+    methods: HashMap<String, HashSet<String>>,
+}
+
+impl Declared {
+    /// Reads the source file. Returns `None` if the file cannot be read or parsed (in this case
+    /// nothing is pruned, because the next build will fail anyway).
+    fn read(source: &Path, manifest_dir: &Path) -> Result<Option<Self>, E> {
+        let Some(file) = fs::read_to_string(source)
+            .ok()
+            .and_then(|content| syn::parse_file(&content).ok())
+        else {
+            return Ok(None);
+        };
+        let mut declared = Declared::default();
+        declared.collect(
+            &file.items,
+            &module_path(&Some(source.to_owned()), manifest_dir)?,
+        );
+        Ok(Some(declared))
+    }
+
+    /// Reads the source file once and returns items declared in it.
+    fn cached<'a>(
+        sources: &'a mut HashMap<PathBuf, Option<Declared>>,
+        source: &Path,
+        manifest_dir: &Path,
+    ) -> Result<Option<&'a Declared>, E> {
+        let source = absolute(source)?;
+        if !sources.contains_key(&source) {
+            let declared = Declared::read(&source, manifest_dir)?;
+            sources.insert(source.to_owned(), declared);
+        }
+        Ok(sources.get(&source).and_then(|declared| declared.as_ref()))
+    }
+
+    fn collect(&mut self, items: &[Item], module: &str) {
+        let is_own = |attrs: &[syn::Attribute]| {
+            attrs.iter().any(|attr| {
+                attr.path()
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "tslink")
+            })
+        };
+        for item in items.iter() {
+            let (attrs, ident) = match item {
+                Item::Mod(item_mod) => {
+                    if let Some((_, items)) = item_mod.content.as_ref() {
+                        self.collect(items, &format!("{module}::{}", item_mod.ident));
+                    }
+                    continue;
+                }
+                Item::Impl(item_impl) => {
+                    let syn::Type::Path(ref ty) = *item_impl.self_ty else {
+                        continue;
+                    };
+                    if let (true, Some(segment)) =
+                        (is_own(&item_impl.attrs), ty.path.segments.last())
+                    {
+                        self.methods
+                            .entry(segment.ident.to_string())
+                            .or_default()
+                            .extend(item_impl.items.iter().filter_map(|item| {
+                                if let ImplItem::Fn(fn_item) = item {
+                                    Some(serialize_name(fn_item.sig.ident.to_string()))
+                                } else {
+                                    None
+                                }
+                            }));
+                    }
+                    continue;
+                }
+                Item::Struct(item) => (&item.attrs, &item.ident),
+                Item::Enum(item) => (&item.attrs, &item.ident),
+                Item::Fn(item) => (&item.attrs, &item.sig.ident),
+                Item::Const(item) => (&item.attrs, &item.ident),
+                Item::Type(item) => (&item.attrs, &item.ident),
+                _ => continue,
+            };
+            if is_own(attrs) {
+                self.paths.insert(format!("{module}::{ident}"));
+            }
+        }
+    }
+}
+
+/// Persistent registry of entities of the crate.
+///
+/// With incremental compilation rustc re-expands only some of `#[tslink]` items, while the in-process
+/// list of entities contains only entities read during the current build. To keep generated artifacts
+/// complete, entities are merged into the registry stored in `target/tslink/{crate}.json`, and artifacts
+/// are generated from the registry.
+///
+/// Entries are identified by the module path of the entity (`crate::model::User`). Declarations
+/// (fields, variants) and methods from `impl` blocks are merged separately, so re-expansion of
+/// only an `impl` block (or only a struct) doesn't lose the other part.
+///
+/// Entities and methods are pruned from the registry if their source file has been removed or
+/// doesn't declare them anymore; on `tslink::finalize!()` (all items of the crate are read) the
+/// registry is replaced with the entities of the current build.
+///
+/// The registry also keeps the list of all files generated for the crate (used by `tslink clean`).
+#[derive(Serialize, Deserialize, Default)]
+struct Registry {
+    entries: Vec<Entry>,
+    #[serde(default)]
+    files: Vec<PathBuf>,
+}
+
+impl Registry {
+    /// Returns the path of the registry file of the current crate. The target folder is
+    /// `CARGO_TARGET_DIR` or `target` next to `Cargo.lock` (the root of the workspace).
+    fn path() -> Result<PathBuf, E> {
+        let config = config::get()?;
+        let target = if let Ok(target) = env::var("CARGO_TARGET_DIR") {
+            PathBuf::from(target)
+        } else {
+            config
+                .manifest_dir
+                .ancestors()
+                .find(|p| p.join("Cargo.lock").exists())
+                .unwrap_or(&config.manifest_dir)
+                .join("target")
+        };
+        let krate = config.package_name()?.replace('-', "_");
+        Ok(target.join(REGISTRY_FOLDER).join(format!("{krate}.json")))
+    }
+
+    fn save(&self, path: &Path) -> Result<(), E> {
+        write_if_changed(path, &serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Reads the registry from the given file. Missing, outdated or corrupted registry is
+    /// considered empty, because it can always be restored with a clean build.
+    fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns the path of the entity read in the current build. Entities created by an `impl`
+    /// block only (the declaration hasn't been read) keep the path of the stored entity.
+    fn path_of(
+        &self,
+        name: &str,
+        def: &NatureDef,
+        sources: &mut HashMap<PathBuf, Option<Declared>>,
+        manifest_dir: &Path,
+    ) -> Result<String, E> {
+        let path = entity_path(name, &def.source, sources, manifest_dir)?;
+        if def.declared {
+            return Ok(path);
+        }
+        Ok(self
+            .entries
+            .iter()
+            .filter(|entry| entry.name == name)
+            .find(|entry| entry.path == path)
+            .or_else(|| self.entries.iter().find(|entry| entry.name == name))
+            .map_or(path, |entry| entry.path.to_owned()))
+    }
+
+    /// Merges the entity read in the current build with the stored entity. The declaration is
+    /// taken from the current build if it has been read; methods are merged by name (methods of
+    /// the current build replace stored methods, new methods are appended).
+    fn merge_entry(
+        path: &str,
+        name: &str,
+        def: &NatureDef,
+        stored: Option<Entry>,
+    ) -> Result<Entry, E> {
+        let Some(stored) = stored else {
+            let methods = def
+                .methods
+                .iter()
+                .map(|(name, source)| Method {
+                    name: name.to_owned(),
+                    source: source.clone(),
+                })
+                .collect();
+            return Entry::new(
+                path,
+                name,
+                def.get().clone(),
+                &def.module,
+                &def.source,
+                methods,
+            );
+        };
+        let fresh = def
+            .get()
+            .get_methods()
+            .into_iter()
+            .filter_map(|method| method_name(method).map(|name| (name, method)))
+            .collect::<Vec<(&str, &Nature)>>();
+        let mut methods: Vec<Nature> = Vec::new();
+        let mut records: Vec<Method> = Vec::new();
+        for method in stored.nature.get_methods().into_iter() {
+            let Some(method_name) = method_name(method) else {
+                continue;
+            };
+            let source = def
+                .methods
+                .iter()
+                .find(|(name, _)| name == method_name)
+                .map_or(stored.method_source(method_name).cloned(), |(_, source)| {
+                    source.clone()
+                });
+            methods.push(
+                fresh
+                    .iter()
+                    .find(|(name, _)| *name == method_name)
+                    .map_or(method.clone(), |(_, method)| (*method).clone()),
+            );
+            records.push(Method {
+                name: method_name.to_owned(),
+                source,
+            });
+        }
+        for (method_name, method) in fresh.into_iter() {
+            if records.iter().any(|record| record.name == method_name) {
+                continue;
+            }
+            methods.push(method.clone());
+            records.push(Method {
+                name: method_name.to_owned(),
+                source: def
+                    .methods
+                    .iter()
+                    .find(|(name, _)| name == method_name)
+                    .map_or(def.source.clone(), |(_, source)| source.clone()),
+            });
+        }
+        let (mut nature, module, source) = if def.declared {
+            (def.get().clone(), &def.module, &def.source)
+        } else {
+            (stored.nature.clone(), &stored.module, &stored.source)
+        };
+        nature.set_methods(methods);
+        Entry::new(path, name, nature, module, source, records)
+    }
+
+    /// Puts entities read in the current build in the order of reading. Other entities of
+    /// the registry keep following the entity, which precedes them in the registry.
+    ///
+    /// Returns paths of entities read in the current build and `true` if some entry has been
+    /// added or changed.
+    fn merge(&mut self, natures: &Natures) -> Result<(HashSet<String>, bool), E> {
+        let manifest_dir = config::get()?.manifest_dir;
+        let mut sources: HashMap<PathBuf, Option<Declared>> = HashMap::new();
+        let mut read: Vec<(String, &String, &NatureDef)> = Vec::new();
+        for (name, def) in natures.defs() {
+            read.push((
+                self.path_of(name, def, &mut sources, &manifest_dir)?,
+                name,
+                def,
+            ));
+        }
+        let paths = read
+            .iter()
+            .map(|(path, ..)| path.to_owned())
+            .collect::<HashSet<String>>();
+        let mut stored: HashMap<String, Entry> = HashMap::new();
+        let mut leading: Vec<Entry> = Vec::new();
+        let mut following: HashMap<String, Vec<Entry>> = HashMap::new();
+        let mut anchor: Option<String> = None;
+        let mut changed = false;
+        for entry in self.entries.drain(..) {
+            if paths.contains(&entry.path) {
+                anchor = Some(entry.path.to_owned());
+                stored.insert(entry.path.to_owned(), entry);
+            } else if read
+                .iter()
+                .any(|(_, name, def)| def.declared && **name == entry.name)
+            {
+                // The entity has been moved to another module
+                changed = true;
+            } else if let Some(anchor) = anchor.as_ref() {
+                following.entry(anchor.to_owned()).or_default().push(entry);
+            } else {
+                leading.push(entry);
+            }
+        }
+        let mut entries = leading;
+        for (path, name, def) in read.into_iter() {
+            let previous = stored.remove(&path);
+            let hash = previous.as_ref().map(|entry| entry.hash.to_owned());
+            let entry = Registry::merge_entry(&path, name, def, previous)?;
+            changed = changed || hash.as_ref() != Some(&entry.hash);
+            entries.push(entry);
+            if let Some(rest) = following.remove(&path) {
+                entries.extend(rest);
+            }
+        }
+        self.entries = entries;
+        Ok((paths, changed))
+    }
+
+    /// Removes entities and methods, which aren't read in the current build and aren't declared
+    /// in their source files anymore. Returns `true` if something has been removed.
+    fn prune(&mut self, read: &HashSet<String>, natures: &Natures) -> Result<bool, E> {
+        let manifest_dir = config::get()?.manifest_dir;
+        let mut sources: HashMap<PathBuf, Option<Declared>> = HashMap::new();
+        let mut changed = false;
+        let mut entries = Vec::new();
+        for mut entry in self.entries.drain(..) {
+            if !read.contains(&entry.path) {
+                let Some(source) = entry.source.as_ref() else {
+                    changed = true;
+                    continue;
+                };
+                if !source.exists() {
+                    changed = true;
+                    continue;
+                }
+                if Declared::cached(&mut sources, source, &manifest_dir)?
+                    .is_some_and(|declared| !declared.paths.contains(&entry.path))
+                {
+                    changed = true;
+                    continue;
+                }
+            }
+            let fresh = natures
+                .get_entity(&entry.name)
+                .map(|nature| {
+                    nature
+                        .get_methods()
+                        .into_iter()
+                        .filter_map(method_name)
+                        .map(|name| name.to_owned())
+                        .collect::<HashSet<String>>()
+                })
+                .unwrap_or_default();
+            let mut removed: Vec<String> = Vec::new();
+            for method in entry
+                .nature
+                .get_methods()
+                .into_iter()
+                .filter_map(method_name)
+            {
+                if fresh.contains(method) {
+                    continue;
+                }
+                let exists = match entry.method_source(method) {
+                    Some(source) if source.exists() => {
+                        Declared::cached(&mut sources, source, &manifest_dir)?.is_none_or(
+                            |declared| {
+                                declared
+                                    .methods
+                                    .get(&entry.name)
+                                    .is_some_and(|methods| methods.contains(method))
+                            },
+                        )
+                    }
+                    _ => false,
+                };
+                if !exists {
+                    removed.push(method.to_owned());
+                }
+            }
+            if !removed.is_empty() {
+                changed = true;
+                let methods = entry
+                    .nature
+                    .get_methods()
+                    .into_iter()
+                    .filter(|method| {
+                        method_name(method).is_some_and(|name| !removed.iter().any(|r| r == name))
+                    })
+                    .cloned()
+                    .collect::<Vec<Nature>>();
+                let records = entry
+                    .methods
+                    .iter()
+                    .filter(|method| !removed.contains(&method.name))
+                    .cloned()
+                    .collect::<Vec<Method>>();
+                let mut nature = entry.nature.clone();
+                nature.set_methods(methods);
+                entry = Entry::new(
+                    &entry.path,
+                    &entry.name,
+                    nature,
+                    &entry.module,
+                    &entry.source,
+                    records,
+                )?;
+            }
+            entries.push(entry);
+        }
+        self.entries = entries;
+        Ok(changed)
+    }
+
+    fn natures(&self) -> Result<Natures, E> {
+        let mut natures = Natures::new();
+        for entry in self.entries.iter() {
+            natures.set_source(entry.source.clone());
+            natures.insert(&entry.name, entry.nature.clone(), entry.module.clone())?;
+        }
+        Ok(natures)
+    }
+}
+
+/// Merges entities read in the current build into the persistent registry and returns
+/// all entities of the crate. The registry isn't written if nothing has changed or generated
+/// files are only checked (`tslink check`).
+///
+/// `complete` should be `true` if all items of the crate have been read (`tslink::finalize!()`);
+/// in this case entities of the registry are replaced with the given entities.
+///
+/// # Errors
+/// Returns an error if the registry cannot be serialized or written.
+pub fn sync(natures: &Natures, complete: bool) -> Result<Natures, E> {
+    let path = Registry::path()?;
+    let mut registry = Registry::load(&path);
+    let mut changed = !path.exists();
+    if complete {
+        changed = changed || !registry.entries.is_empty();
+        registry.entries.clear();
+    }
+    let (read, merged) = registry.merge(natures)?;
+    let pruned = registry.prune(&read, natures)?;
+    if (changed || merged || pruned) && !Journal::is_check()? {
+        registry.save(&path)?;
+    }
+    registry.natures()
+}
+
+/// Adds the given paths to the list of generated files of the crate.
+///
+/// # Errors
+/// Returns an error if the registry cannot be serialized or written.
+pub fn record_files(files: &[PathBuf]) -> Result<(), E> {
+    let path = Registry::path()?;
+    let mut registry = Registry::load(&path);
+    for file in files.iter() {
+        // Paths are relative to the current folder, which depends on the way of generation
+        let file = absolute(file)?;
+        if !registry.files.contains(&file) {
+            registry.files.push(file);
+        }
+    }
+    registry.save(&path)
+}
+
+/// Returns the list of files generated for the crate (absolute paths).
+///
+/// # Errors
+/// Returns an error if the path to the registry cannot be detected.
+pub fn files() -> Result<Vec<PathBuf>, E> {
+    Ok(Registry::load(&Registry::path()?).files)
+}
+
+/// Removes the registry of the crate and returns the list of files generated for the crate.
+///
+/// # Errors
+/// Returns an error if the registry cannot be removed.
+pub fn remove() -> Result<Vec<PathBuf>, E> {
+    let path = Registry::path()?;
+    let registry = Registry::load(&path);
+    if path.exists() {
+        fs::remove_file(&path)?;
+    }
+    Ok(registry.files)
+}
@@ -0,0 +1,42 @@
+//! Helpers of tests, which generate artifacts of fixture crates (`tests/*` of the repository).
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+use tslink_build::Generator;
+
+static OUTPUT: OnceLock<PathBuf> = OnceLock::new();
+
+/// Returns the folder of the fixture crate `tests/{name}`.
+pub fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../tests")
+        .join(name)
+}
+
+/// Generates artifacts of a crate into `CARGO_TARGET_TMPDIR/{name}` and returns this folder.
+/// `manifest_dir` gets the output folder and returns the folder of the crate.
+///
+/// The configuration of tslink is global, so artifacts are generated once per test binary;
+/// following calls return the output folder of the first call.
+pub fn generate<F: FnOnce(&Path) -> PathBuf>(name: &str, manifest_dir: F) -> &'static Path {
+    OUTPUT.get_or_init(|| {
+        let output = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+        let _ = fs::remove_dir_all(&output);
+        fs::create_dir_all(&output).expect("Create output folder");
+        Generator::new()
+            .manifest_dir(manifest_dir(&output))
+            .crate_root("rs/lib.rs")
+            .output_dir(&output)
+            .run()
+            .expect("Generate artifacts");
+        output
+    })
+}
+
+/// Reads the generated file; the path is relative to the output folder.
+pub fn read(output: &Path, path: &str) -> String {
+    fs::read_to_string(output.join(path)).expect("Read generated file")
+}
@@ -0,0 +1,56 @@
+//! Expands `#[tslink]` and `tslink::finalize!()` for a small crate without
+//! `deferred_generation` and checks, that `finalize!()` doesn't write anything.
+
+use quote::quote;
+use std::{env, fs, path::PathBuf};
+use tslink_build::macros;
+
+const SOURCE: &str = "pub struct User {
+    pub id: u32,
+}
+";
+
+#[test]
+fn finalize_without_deferred_generation() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("finalize");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).expect("Create crate folder");
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]
+name = \"finalize-test\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[package.metadata.tslink]
+node = \"./dist/index.node\"
+",
+    )
+    .expect("Write manifest");
+    fs::write(dir.join("src/lib.rs"), format!("#[tslink]\n{SOURCE}")).expect("Write source");
+    // Configuration is read as by the macro during a build
+    env::set_var("CARGO_MANIFEST_DIR", &dir);
+    env::set_var("CARGO_TARGET_DIR", dir.join("target"));
+    env::set_var("TSLINK_BUILD", "true");
+    env::set_current_dir(&dir).expect("Change current folder");
+
+    let output = macros::tslink(
+        quote! {},
+        SOURCE.parse().expect("Parse source"),
+        Some(dir.join("src/lib.rs")),
+    );
+    assert!(!output.to_string().contains("compile_error"), "{output}");
+    // Files are written by #[tslink] itself
+    let dts = fs::read_to_string(dir.join("dist/lib.d.ts")).expect("Read lib.d.ts");
+    assert!(dts.contains("export interface User {"));
+
+    fs::remove_dir_all(dir.join("dist")).expect("Remove artifacts");
+    let registry = dir.join("target/tslink/finalize_test.json");
+    let entities = fs::read_to_string(&registry).expect("Read registry");
+    assert!(macros::finalize().is_empty());
+    assert!(!dir.join("dist").exists());
+    assert_eq!(
+        entities,
+        fs::read_to_string(&registry).expect("Read registry")
+    );
+}
@@ -0,0 +1,36 @@
+//! Generates artifacts of the crate `tests/napi-rs` and checks class wrappers of `lib.js`.
+//! The runtime behaviour is tested by the crate itself (`yarn run test`).
+
+mod common;
+
+fn read(path: &str) -> String {
+    common::read(
+        common::generate("napi-rs", |_| common::fixture("napi-rs")),
+        path,
+    )
+}
+
+#[test]
+fn static_methods() {
+    let js = read("dist/lib.js");
+    // Instances returned by static methods are wrapped into the class of lib.js
+    assert!(js.contains(
+        "\n    static withValue(value) {\n        return $$StructStaticMethods.#wrap(StructStaticMethods.withValue(value));\n    }\n"
+    ));
+    assert!(js.contains(
+        "\n    static maxValue() {\n        return StructStaticMethods.maxValue();\n    }\n"
+    ));
+    assert!(js.contains(
+        "\n    static #wrap(origin) {\n        $$StructStaticMethods.#wrapped = origin;\n        return new $$StructStaticMethods();\n    }\n"
+    ));
+    assert!(js.contains(
+        "\n    constructor() {\n        if ($$StructStaticMethods.#wrapped !== undefined) {\n"
+    ));
+    // Classes without such methods aren't changed
+    assert!(js.contains(
+        "\n    constructor() {\n        this.#_origin = new StructCustomData();\n    }\n"
+    ));
+    let dts = read("dist/lib.d.ts");
+    assert!(dts.contains("\n    static withValue(value: number): StructStaticMethods;\n"));
+    assert!(!dts.contains("Self"));
+}
@@ -0,0 +1,101 @@
+//! Expands `#[tslink]` for a crate with an inline module in two builds: the second build
+//! re-expands only one of the items (as rustc does with incremental compilation) and checks,
+//! that entities of the inline module are kept in the registry.
+//!
+//! Entities read in a process are kept until the process ends, so each build runs in its own
+//! process (the test runs itself with `TSLINK_REGISTRY_BUILD`).
+
+use quote::quote;
+use std::{env, fs, path::PathBuf, process::Command};
+use tslink_build::macros;
+
+const SOURCE: &str = "mod inner {
+    #[tslink]
+    pub struct User {
+        pub id: u32,
+    }
+}
+
+#[tslink]
+pub fn get_id() -> u32 {
+    0
+}
+";
+
+const USER: &str = "pub struct User {
+    pub id: u32,
+}";
+
+const GET_ID: &str = "pub fn get_id() -> u32 {
+    0
+}";
+
+fn dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("registry")
+}
+
+/// Expands the given items as `#[tslink]` does during a build.
+fn build(items: &[&str]) {
+    let dir = dir();
+    env::set_var("CARGO_MANIFEST_DIR", &dir);
+    env::set_var("CARGO_TARGET_DIR", dir.join("target"));
+    env::set_var("TSLINK_BUILD", "true");
+    env::set_current_dir(&dir).expect("Change current folder");
+    for item in items.iter() {
+        let output = macros::tslink(
+            quote! {},
+            item.parse().expect("Parse item"),
+            Some(dir.join("src/lib.rs")),
+        );
+        assert!(!output.to_string().contains("compile_error"), "{output}");
+    }
+}
+
+/// Runs the test in a separate process, which performs the given build.
+fn spawn(build: &str) {
+    let status = Command::new(env::current_exe().expect("Get test binary"))
+        .args(["inline_module", "--exact", "--test-threads=1"])
+        .env("TSLINK_REGISTRY_BUILD", build)
+        .status()
+        .expect("Run build");
+    assert!(status.success(), "build \"{build}\" failed");
+}
+
+#[test]
+fn inline_module() {
+    match env::var("TSLINK_REGISTRY_BUILD").as_deref() {
+        Ok("full") => return build(&[USER, GET_ID]),
+        Ok("incremental") => return build(&[GET_ID]),
+        _ => {}
+    }
+    let dir = dir();
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).expect("Create crate folder");
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]
+name = \"registry-test\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[package.metadata.tslink]
+node = \"./dist/index.node\"
+",
+    )
+    .expect("Write manifest");
+    fs::write(dir.join("src/lib.rs"), SOURCE).expect("Write source");
+
+    spawn("full");
+    let registry =
+        fs::read_to_string(dir.join("target/tslink/registry_test.json")).expect("Read registry");
+    assert!(registry.contains("\"path\": \"crate::inner::User\""));
+    assert!(registry.contains("\"path\": \"crate::get_id\""));
+
+    spawn("incremental");
+    let dts = fs::read_to_string(dir.join("dist/lib.d.ts")).expect("Read lib.d.ts");
+    assert!(dts.contains("export interface User {"), "{dts}");
+    assert!(
+        dts.contains("export declare function get_id(): number;"),
+        "{dts}"
+    );
+}
@@ -0,0 +1,86 @@
+//! Expands `#[tslink(class)]` items of a crate with `skip_private = true` and checks, that
+//! non-pub members are skipped and non-pub members with own attributes are reported.
+
+use quote::quote;
+use std::{env, fs, path::PathBuf};
+use tslink_build::macros;
+
+const STRUCT: &str = "struct Counter {
+    pub value: u32,
+    step: u32,
+    #[tslink(include)]
+    limit: u32,
+}";
+
+const IMPL: &str = "impl Counter {
+    pub fn inc(&mut self) -> u32 {
+        self.value
+    }
+    fn reset(&mut self) {}
+    #[tslink(include)]
+    fn max(&self) -> u32 {
+        self.limit
+    }
+}";
+
+const ANNOTATED: &str = "impl Counter {
+    #[tslink(getter)]
+    fn step(&self) -> u32 {
+        self.step
+    }
+}";
+
+#[test]
+fn skip_private() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("skip-private");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).expect("Create crate folder");
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]
+name = \"skip-private-test\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[package.metadata.tslink]
+node = \"./dist/index.node\"
+skip_private = true
+",
+    )
+    .expect("Write manifest");
+    let source = format!("#[tslink(class)]\n{STRUCT}\n\n#[tslink(class)]\n{IMPL}\n");
+    fs::write(dir.join("src/lib.rs"), source).expect("Write source");
+    // Configuration is read as by the macro during a build
+    env::set_var("CARGO_MANIFEST_DIR", &dir);
+    env::set_var("CARGO_TARGET_DIR", dir.join("target"));
+    env::set_var("TSLINK_BUILD", "true");
+    env::set_current_dir(&dir).expect("Change current folder");
+
+    for item in [STRUCT, IMPL] {
+        let output = macros::tslink(
+            quote! { class },
+            item.parse().expect("Parse item"),
+            Some(dir.join("src/lib.rs")),
+        );
+        assert!(!output.to_string().contains("compile_error"), "{output}");
+    }
+    let dts = fs::read_to_string(dir.join("dist/lib.d.ts")).expect("Read lib.d.ts");
+    assert!(dts.contains("    value: number;\n"), "{dts}");
+    assert!(dts.contains("    limit: number;\n"), "{dts}");
+    assert!(dts.contains("    inc(): number;\n"), "{dts}");
+    assert!(dts.contains("    max(): number;\n"), "{dts}");
+    assert!(!dts.contains("step"), "{dts}");
+    assert!(!dts.contains("reset"), "{dts}");
+
+    let output = macros::tslink(
+        quote! { class },
+        ANNOTATED.parse().expect("Parse item"),
+        Some(dir.join("src/lib.rs")),
+    )
+    .to_string();
+    assert!(output.contains("compile_error"), "{output}");
+    assert!(
+        output.contains("make it pub or add #[tslink(include)]"),
+        "{output}"
+    );
+}
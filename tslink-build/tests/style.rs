@@ -0,0 +1,54 @@
+//! Generates artifacts of the crate `tests/style` (single quotes, no semicolons, tabs) and checks
+//! that literals, comments and statements are rendered correctly.
+
+mod common;
+
+fn read(path: &str) -> String {
+    common::read(
+        common::generate("style", |_| common::fixture("style")),
+        path,
+    )
+}
+
+#[test]
+fn style() {
+    let js = read("dist/lib.js");
+    let dts = read("dist/lib.d.ts");
+
+    assert!(js.starts_with("// Generated by tslink"));
+    assert!(js.contains("\n// DO NOT EDIT: manual changes will be lost on the next build.\n"));
+    // String literals of constants are rendered with configured quotes
+    assert!(js.contains("\nexports.GREETING = 'It\\'s a \"greeting\"; // not a comment'\n"));
+    assert!(js.contains("\nexports.PATTERN = '/* ${not} a template */'\n"));
+    assert!(dts.contains("\nexport const GREETING = 'It\\'s a \"greeting\"; // not a comment'\n"));
+    assert!(js.contains("\nconst path = require('path')\n"));
+    // Quotes in template literals are a part of the content
+    assert!(js.contains(
+        "\t\tthrow new TypeError(`Invalid argument \"level\" of \"label\": expected u8`)\n"
+    ));
+    assert!(js.contains(
+        "\t\treturn Promise.reject(new TypeError(`Invalid argument \"ms\" of \"delayed\": expected u32`))\n"
+    ));
+    assert!(js.contains("throw new Error(`Fail to find native module in: ${modulePath}`)\n"));
+    assert!(js.contains(
+        "new Error(`Function/method returns error; property [err] = ${typeof e === 'object' && e !== null ? JSON.stringify(e) : e}`)\n"
+    ));
+    // Statements and members aren't terminated with semicolons; nested blocks are indented
+    assert!(js.contains("\n\treturn label(name, level)\n}\n"));
+    assert!(js.contains("\n\t}).catch((e) => {\n\t\tif (e instanceof Error) {\n\t\t\treturn Promise.reject(e)\n\t\t}\n"));
+    assert!(js.contains("\n\t\treturn this.#_origin.inc(step)\n\t}\n"));
+    assert!(dts.contains("\n\tvalue: number\n\tconstructor()\n\tinc(step: number): number\n}\n"));
+    for (file, content) in [("lib.js", &js), ("lib.d.ts", &dts)] {
+        for line in content.lines() {
+            assert!(!line.ends_with(';'), "{file}: semicolon in \"{line}\"");
+            assert!(
+                !line.ends_with([' ', '\t']),
+                "{file}: trailing whitespace in \"{line}\""
+            );
+            assert!(
+                !line.starts_with(' '),
+                "{file}: indentation with spaces in \"{line}\""
+            );
+        }
+    }
+}
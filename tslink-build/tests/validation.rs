@@ -0,0 +1,173 @@
+//! Generates artifacts of the crate `tests/validation` and checks rendered schemas and checks
+//! of values. The runtime behaviour is tested by the crate itself (`yarn run test`).
+
+mod common;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+fn read(path: &str) -> String {
+    common::read(
+        common::generate("validation", |_| common::fixture("validation")),
+        path,
+    )
+}
+
+#[test]
+fn json_schema() {
+    let schema: Value = serde_json::from_str(&read("dist/schema.json")).expect("Parse schema");
+    let defs = &schema["$defs"];
+    assert_eq!(
+        defs["LIMIT"],
+        json!({ "type": "integer", "minimum": 0, "maximum": 255, "const": 10 })
+    );
+    assert_eq!(defs["RATIO"], json!({ "type": "number", "const": -1.5 }));
+    // Expressions aren't evaluated
+    assert_eq!(
+        defs["SIZE"],
+        json!({ "type": "integer", "minimum": 0, "maximum": 4294967295u32 })
+    );
+    assert_eq!(
+        defs["Role"],
+        json!({ "type": "string", "enum": ["Admin", "Guest"] })
+    );
+    let user = &defs["User"];
+    assert_eq!(
+        user["properties"]["age"],
+        json!({ "type": "integer", "minimum": 0, "maximum": 255 })
+    );
+    assert_eq!(
+        user["properties"]["role"],
+        json!({ "$ref": "#/$defs/Role" })
+    );
+    assert_eq!(
+        user["properties"]["scores"]["anyOf"][0]["additionalProperties"],
+        json!({ "type": "integer", "minimum": -32768, "maximum": 32767 })
+    );
+    assert_eq!(
+        user["required"],
+        json!(["id", "name", "age", "tags", "role", "scores"])
+    );
+    assert_eq!(user["additionalProperties"], json!(false));
+}
+
+/// Mirror of `Event` of the crate; the payload of `Created` is any value.
+#[derive(Serialize)]
+enum Event {
+    Created(Value),
+    Moved(u32, u32),
+    Removed { id: u32 },
+    Cleared,
+}
+
+#[test]
+fn enum_schema() {
+    let schema: Value = serde_json::from_str(&read("dist/schema.json")).expect("Parse schema");
+    let event = &schema["$defs"]["Event"];
+    assert_eq!(event["oneOf"][0], json!({ "const": "Cleared" }));
+    let object = &event["oneOf"][1];
+    assert_eq!(object["type"], json!("object"));
+    assert_eq!(
+        object["properties"]["Moved"],
+        json!({
+            "type": "array",
+            "prefixItems": [
+                { "type": "integer", "minimum": 0, "maximum": 4294967295u32 },
+                { "type": "integer", "minimum": 0, "maximum": 4294967295u32 }
+            ],
+            "items": false,
+            "minItems": 2
+        })
+    );
+    assert!(object["properties"].get("Cleared").is_none());
+    // Values serialized by serde match the schema
+    assert_eq!(
+        serde_json::to_value(Event::Cleared).expect("Serialize event"),
+        event["oneOf"][0]["const"]
+    );
+    for value in [
+        Event::Created(json!({})),
+        Event::Moved(1, 2),
+        Event::Removed { id: 1 },
+    ] {
+        let value = serde_json::to_value(value).expect("Serialize event");
+        let (name, _) = value
+            .as_object()
+            .and_then(|value| value.iter().next())
+            .expect("Variant with data is an object");
+        assert!(object["properties"].get(name).is_some(), "{value}");
+    }
+}
+
+#[test]
+fn constants() {
+    // Suffixes of literals aren't valid in JavaScript
+    let js = read("dist/lib.js");
+    assert!(js.contains("\nexports.LIMIT = 1_0;\n"));
+    assert!(js.contains("\nexports.RATIO = -1.5;\n"));
+    assert!(js.contains("\nexports.SIZE = 2 * 5;\n"));
+    let ts = read("ts/bindings/data.ts");
+    assert!(ts.contains("\nexport const RATIO = -1.5;\n"));
+}
+
+#[test]
+fn json_results() {
+    let js = read("dist/lib.js");
+    assert!(js.contains("return JSON.parse(createUser(id, name));"));
+}
+
+#[test]
+fn zod() {
+    let ts = read("ts/bindings/data.ts");
+    assert!(ts.contains("\nimport { z } from \"zod\";\n"));
+    assert!(ts.contains(
+        "\nexport const RoleSchema: z.ZodType<Role, z.ZodTypeDef, unknown> = z.nativeEnum(Role);\n"
+    ));
+    assert!(ts.contains(
+        "\nexport const UserSchema: z.ZodType<User, z.ZodTypeDef, unknown> = z.object({\n"
+    ));
+    assert!(ts.contains("\n    role: z.lazy(() => RoleSchema),\n"));
+    let js = read("dist/lib.js");
+    assert!(js.contains("\nconst { z } = require(\"zod\");\n"));
+    assert!(js.contains("\nconst RoleSchema = z.enum([\"Admin\", \"Guest\"]);\n"));
+    // Bound arguments are validated before serialization
+    assert!(js.contains("renameUser(JSON.stringify($$validate(UserSchema, user)), name)"));
+    let package: Value =
+        serde_json::from_str(&read("dist/package.json")).expect("Parse package.json");
+    assert_eq!(package["dependencies"]["zod"], json!("^3.23.0"));
+}
+
+#[test]
+fn guards() {
+    let js = read("dist/lib.js");
+    assert!(js.contains(
+        "\nfunction isRole(value) {\n    return (value === \"Admin\" || value === \"Guest\");\n}\n"
+    ));
+    assert!(js.contains("\nexports.isUser = isUser;\n"));
+    // Maps are passed to lib.js as plain objects
+    assert!(js.contains("(typeof value.scores === \"object\" && value.scores !== null && !Array.isArray(value.scores) && Object.values(value.scores).every((item) => typeof item === \"number\"))"));
+    let ts = read("ts/bindings/data.ts");
+    assert!(ts.contains("\nexport function isUser(value: unknown): value is User {\n"));
+    // Maps are accepted as instances of Map and plain objects
+    assert!(ts.contains("(value.scores instanceof Map ? Array.from(value.scores).every(([key, item]) => typeof key === \"string\" && typeof item === \"number\") : (typeof value.scores === \"object\" && value.scores !== null && !Array.isArray(value.scores) && Object.values(value.scores).every((item) => typeof item === \"number\")))"));
+    let dts = read("dist/lib.d.ts");
+    assert!(dts.contains("\nexport declare function isUser(value: unknown): value is User;\n"));
+}
+
+#[test]
+fn validate_args() {
+    let js = read("dist/lib.js");
+    assert!(js.contains(
+        "\n    if (!(typeof a === \"number\" && Number.isInteger(a) && a >= 0 && a <= 255)) {\n        throw new TypeError(`Invalid argument \"a\" of \"sum\": expected u8`);\n    }\n"
+    ));
+    assert!(js.contains(
+        "\n    if (!(c === null || c === undefined || (typeof c === \"number\" && Number.isInteger(c) && c >= 0 && c <= 4294967295))) {\n"
+    ));
+    assert!(js.contains("expected Vec<u8>`);"));
+    // Async functions return a rejected promise instead of throwing
+    assert!(js.contains(
+        "return Promise.reject(new TypeError(`Invalid argument \"value\" of \"incLater\": expected u8`));"
+    ));
+    // Bound arguments are checked with type guards of bound entities
+    assert!(js.contains("\n    if (!isUser(user)) {\n"));
+}
@@ -0,0 +1,62 @@
+//! Generates artifacts of the crate `tests/wasm-bindgen` (CommonJS, `--target nodejs`) and checks
+//! the loading of the module of wasm-bindgen and `package.json`. The runtime behaviour is tested
+//! by the crate itself (`yarn run test`).
+
+mod common;
+
+use serde_json::{json, Value};
+
+fn read(path: &str) -> String {
+    common::read(
+        common::generate("wasm-bindgen", |_| common::fixture("wasm-bindgen")),
+        path,
+    )
+}
+
+#[test]
+fn prelude() {
+    let js = read("dist/lib.js");
+    assert!(js.contains("\nconst nativeModuleRef = require(\"./binding.js\");\n"));
+    // The module of wasm-bindgen is loaded as is, without lookup of a ".node" file
+    assert!(!js.contains("function native()"));
+    assert!(!js.contains("require(\"fs\")"));
+}
+
+#[test]
+fn wrappers() {
+    let js = read("dist/lib.js");
+    assert!(js.contains(
+        "return JSON.parse(movePoint(JSON.stringify(point), JSON.stringify(direction), distance));"
+    ));
+    assert!(js.contains("\nfunction $$checkDistance(distance) {\n"));
+    assert!(js.contains("\nexports.checkDistance = $$checkDistance;\n"));
+    assert!(js.contains("return nativeModuleRef.directionStep(JSON.stringify(value));"));
+    assert!(js.contains("\nexports.toBig = toBig;"));
+    let dts = read("dist/lib.d.ts");
+    assert!(dts.contains("export declare function toBig(value: BigInt): BigInt;"));
+}
+
+#[test]
+fn package() {
+    let package: Value =
+        serde_json::from_str(&read("dist/package.json")).expect("Parse package.json");
+    assert_eq!(
+        package["files"],
+        json!([
+            "binding.js",
+            "binding_bg.js",
+            "binding_bg.wasm",
+            "lib.js",
+            "lib.d.ts"
+        ])
+    );
+    assert_eq!(
+        package["sideEffects"],
+        json!(["./binding.js", "./snippets/*"])
+    );
+    assert_eq!(package["main"], json!("lib.js"));
+    // "browser", "type" and "exports" are written only for ES modules
+    assert!(package.get("browser").is_none());
+    assert!(package.get("type").is_none());
+    assert!(package.get("exports").is_none());
+}
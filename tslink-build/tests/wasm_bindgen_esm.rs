@@ -0,0 +1,64 @@
+//! Generates artifacts of the crate `tests/wasm-bindgen` as an ES module for bundlers and checks
+//! the import of the module of wasm-bindgen and `package.json`.
+
+mod common;
+
+use serde_json::{json, Value};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Copies the crate with `module_format = "esm"` into the output folder and returns the folder
+/// of the copy.
+fn copy(output: &Path) -> PathBuf {
+    let origin = common::fixture("wasm-bindgen");
+    let manifest_dir = output.join("crate");
+    fs::create_dir_all(manifest_dir.join("rs")).expect("Create crate folder");
+    let manifest = fs::read_to_string(origin.join("Cargo.toml")).expect("Read manifest");
+    fs::write(
+        manifest_dir.join("Cargo.toml"),
+        manifest.replace("module_format = \"cjs\"", "module_format = \"esm\""),
+    )
+    .expect("Write manifest");
+    fs::copy(origin.join("rs/lib.rs"), manifest_dir.join("rs/lib.rs")).expect("Copy source");
+    manifest_dir
+}
+
+fn read(path: &str) -> String {
+    common::read(common::generate("wasm-bindgen-esm", copy), path)
+}
+
+#[test]
+fn prelude() {
+    let js = read("dist/lib.js");
+    assert!(js.contains("\nimport * as nativeModuleRef from \"./binding.js\";\n"));
+    assert!(!js.contains("createRequire"));
+    assert!(js.contains("\nexport const Direction = Object.freeze({\n"));
+}
+
+#[test]
+fn package() {
+    let package: Value =
+        serde_json::from_str(&read("dist/package.json")).expect("Parse package.json");
+    assert_eq!(package["type"], json!("module"));
+    assert_eq!(package["browser"], json!("lib.js"));
+    assert_eq!(
+        package["exports"],
+        json!({ ".": { "types": "./lib.d.ts", "import": "./lib.js" } })
+    );
+    assert_eq!(
+        package["sideEffects"],
+        json!(["./binding.js", "./snippets/*"])
+    );
+    assert_eq!(
+        package["files"],
+        json!([
+            "binding.js",
+            "binding_bg.js",
+            "binding_bg.wasm",
+            "lib.js",
+            "lib.d.ts"
+        ])
+    );
+}
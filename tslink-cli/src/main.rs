@@ -0,0 +1,123 @@
+use std::{env, path::PathBuf, process::ExitCode};
+use tslink_build::Generator;
+
+const HELP: &str = "tslink - generates TypeScript/JavaScript bindings of a crate
+
+USAGE:
+    tslink <COMMAND> [OPTIONS]
+
+COMMANDS:
+    generate    Write all artifacts (*.ts, lib.d.ts, lib.js, package.json)
+    check       Exit with non-zero code if existing artifacts differ from the current sources
+    clean       Remove all files generated by tslink
+
+OPTIONS:
+    --manifest-path <PATH>    Path to Cargo.toml (default: ./Cargo.toml)
+    --crate-root <PATH>       Root file of the crate relative to Cargo.toml (default: src/lib.rs)
+    -h, --help                Print help";
+
+enum Command {
+    Generate,
+    Check,
+    Clean,
+}
+
+struct Args {
+    command: Command,
+    manifest_path: Option<PathBuf>,
+    crate_root: Option<PathBuf>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut command = None;
+        let mut manifest_path = None;
+        let mut crate_root = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "generate" | "check" | "clean" if command.is_some() => {
+                    return Err(format!(
+                        "Only one command can be used; extra command: {arg}"
+                    ))
+                }
+                "generate" => command = Some(Command::Generate),
+                "check" => command = Some(Command::Check),
+                "clean" => command = Some(Command::Clean),
+                "--manifest-path" | "--crate-root" => {
+                    let value = args
+                        .next()
+                        .map(PathBuf::from)
+                        .ok_or(format!("Value of {arg} is missing"))?;
+                    if arg == "--manifest-path" {
+                        manifest_path = Some(value);
+                    } else {
+                        crate_root = Some(value);
+                    }
+                }
+                _ => return Err(format!("Unknown argument: {arg}")),
+            }
+        }
+        Ok(Some(Self {
+            command: command.ok_or(String::from("Command is missing"))?,
+            manifest_path,
+            crate_root,
+        }))
+    }
+}
+
+fn run(args: Args) -> Result<bool, String> {
+    // Paths in the configuration are relative to the folder of Cargo.toml (as for build scripts)
+    if let Some(manifest_path) = args.manifest_path.as_ref() {
+        if let Some(dir) = manifest_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            env::set_current_dir(dir)
+                .map_err(|e| format!("Fail to open {}: {e}", dir.display()))?;
+        }
+    }
+    let cwd = env::current_dir().map_err(|e| e.to_string())?;
+    let mut generator = Generator::new().manifest_dir(&cwd);
+    if let Some(crate_root) = args.crate_root {
+        generator = generator.crate_root(crate_root);
+    }
+    match args.command {
+        Command::Generate => {
+            generator.run().map_err(|e| e.to_string())?;
+            Ok(true)
+        }
+        Command::Check => {
+            let changed = generator.check().map_err(|e| e.to_string())?;
+            for file in changed.iter() {
+                eprintln!("outdated: {}", file.display());
+            }
+            Ok(changed.is_empty())
+        }
+        Command::Clean => {
+            for file in generator.clean().map_err(|e| e.to_string())? {
+                println!("removed: {}", file.display());
+            }
+            Ok(true)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{HELP}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{HELP}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Runs the binary `tslink` with different arguments and checks exit codes, messages and
+//! artifacts of a small crate.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+const MANIFEST: &str = r#"[package]
+name = "cli-test"
+version = "0.1.0"
+edition = "2021"
+
+[package.metadata.tslink]
+node = "./dist/index.node"
+"#;
+
+const SOURCE: &str = r#"use tslink::tslink;
+
+#[tslink]
+pub struct User {
+    pub id: u32,
+    pub name: String,
+}
+
+#[tslink]
+pub fn get_user(id: u32) -> User {
+    User { id, name: String::new() }
+}
+"#;
+
+/// Creates a crate in a new folder and returns the folder.
+fn create(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("cli")
+        .join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).expect("Create crate folder");
+    fs::write(dir.join("Cargo.toml"), MANIFEST).expect("Write manifest");
+    fs::write(dir.join("src/lib.rs"), SOURCE).expect("Write source");
+    dir
+}
+
+/// Runs `tslink` with given arguments; the registry is stored in the folder of the crate.
+fn tslink(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_tslink"))
+        .args(args)
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .output()
+        .expect("Run tslink")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).to_string()
+}
+
+/// Returns contents of all files in the folder (recursively) by their paths.
+fn snapshot(dir: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+    let mut files = BTreeMap::new();
+    for entry in fs::read_dir(dir).expect("Read folder") {
+        let path = entry.expect("Read entry").path();
+        if path.is_dir() {
+            files.extend(snapshot(&path));
+        } else {
+            files.insert(path.clone(), fs::read(&path).expect("Read file"));
+        }
+    }
+    files
+}
+
+#[test]
+fn help() {
+    let dir = create("help");
+    for arg in ["-h", "--help"] {
+        let output = tslink(&dir, &[arg]);
+        assert!(output.status.success());
+        assert!(stdout(&output).contains("USAGE:\n    tslink <COMMAND> [OPTIONS]"));
+    }
+    // Help is printed even if other arguments are invalid
+    assert!(tslink(&dir, &["generate", "--help"]).status.success());
+}
+
+#[test]
+fn invalid_arguments() {
+    let dir = create("invalid_arguments");
+    for (args, msg) in [
+        (vec![], "error: Command is missing"),
+        (vec!["build"], "error: Unknown argument: build"),
+        (
+            vec!["generate", "clean"],
+            "error: Only one command can be used; extra command: clean",
+        ),
+        (
+            vec!["check", "--crate-root", "src/lib.rs", "check"],
+            "error: Only one command can be used; extra command: check",
+        ),
+        (
+            vec!["generate", "--verbose"],
+            "error: Unknown argument: --verbose",
+        ),
+        (
+            vec!["generate", "--manifest-path"],
+            "error: Value of --manifest-path is missing",
+        ),
+        (
+            vec!["check", "--crate-root"],
+            "error: Value of --crate-root is missing",
+        ),
+        (
+            vec!["--crate-root", "src/lib.rs"],
+            "error: Command is missing",
+        ),
+    ] {
+        let output = tslink(&dir, &args);
+        assert_eq!(output.status.code(), Some(1), "{args:?}");
+        assert!(stderr(&output).starts_with(msg), "{args:?}");
+        // Usage is printed after the error
+        assert!(stderr(&output).contains("USAGE:"), "{args:?}");
+    }
+    assert!(!dir.join("dist").exists());
+}
+
+#[test]
+fn generate_check_clean() {
+    let dir = create("generate_check_clean");
+    // Nothing is generated yet
+    let output = tslink(&dir, &["check"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("outdated: "));
+    assert!(!dir.join("dist").exists());
+    assert!(!dir.join("target").exists());
+
+    let output = tslink(&dir, &["generate"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let dts = fs::read_to_string(dir.join("dist/lib.d.ts")).expect("Read lib.d.ts");
+    assert!(dts.contains("export declare function get_user(id: number): User;"));
+    assert!(dir.join("dist/lib.js").exists());
+    assert!(dir.join("dist/package.json").exists());
+    assert!(dir.join("target/tslink/cli_test.json").exists());
+
+    let output = tslink(&dir, &["check"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    // Outdated artifacts are reported, but neither artifacts nor the registry are written
+    fs::write(
+        dir.join("src/lib.rs"),
+        SOURCE.replace("pub name: String,", "pub name: String,\n    pub age: u8,"),
+    )
+    .expect("Write source");
+    let before = snapshot(&dir);
+    let output = tslink(&dir, &["check"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("lib.d.ts"));
+    assert_eq!(before, snapshot(&dir));
+
+    let output = tslink(&dir, &["clean"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("removed: "));
+    assert!(!dir.join("dist/lib.d.ts").exists());
+    assert!(!dir.join("dist/lib.js").exists());
+}
+
+#[test]
+fn manifest_path() {
+    let dir = create("manifest_path");
+    fs::create_dir_all(dir.join("rs")).expect("Create folder");
+    fs::rename(dir.join("src/lib.rs"), dir.join("rs/lib.rs")).expect("Move source");
+    // Paths of artifacts are relative to the folder of Cargo.toml
+    let output = tslink(
+        dir.parent().expect("Parent folder"),
+        &[
+            "generate",
+            "--manifest-path",
+            "manifest_path/Cargo.toml",
+            "--crate-root",
+            "rs/lib.rs",
+        ],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(dir.join("dist/lib.d.ts").exists());
+    let output = tslink(&dir, &["generate"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).starts_with("error: "));
+}